@@ -1,3 +1,4 @@
+#![allow(dead_code)]
 pub mod item_container;
 #[allow(unused_imports)]
 use crossterm::{
@@ -31,16 +32,16 @@ impl Colors {
 pub struct Block;
 impl Block {
     pub fn disabled() -> ratatui::widgets::Block<'static> {
-        return ratatui::widgets::Block::default()
+        ratatui::widgets::Block::default()
             .borders(Borders::ALL)
-            .style(Style::default().fg(Colors::GRAY));
+            .style(Style::default().fg(Colors::GRAY))
     }
 
     pub fn selected() -> ratatui::widgets::Block<'static> {
-        return ratatui::widgets::Block::default()
+        ratatui::widgets::Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Double)
-            .style(Style::default().fg(Colors::WHITE));
+            .style(Style::default().fg(Colors::WHITE))
     }
 }
 
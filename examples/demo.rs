@@ -143,7 +143,7 @@ impl TextContainer {
             String::from("2. Read in the park"),
             String::from("3. Go to dinner with friends"),
         ];
-        let containers = vec![
+        let containers = [
             TextContainer::new("Monday", monday),
             TextContainer::new("Tuesday", tuesday),
             TextContainer::new("Wednesday", wednesday),
@@ -182,7 +182,7 @@ impl Widget for TextContainer {
         let mut lines = vec![Line::styled(self.title, self.style)];
         if self.expand {
             lines.push(Line::from(String::new()));
-            lines.extend(self.content.into_iter().map(|x| Line::from(x)));
+            lines.extend(self.content.into_iter().map(Line::from));
             lines.push(Line::from(String::new()));
         }
         Paragraph::new(lines)
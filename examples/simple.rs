@@ -8,7 +8,7 @@ use tui_widget_list::{ListBuilder, ListState, ListView};
 fn main() -> Result<()> {
     let mut terminal = Terminal::init()?;
 
-    App::default().run(&mut terminal).unwrap();
+    App.run(&mut terminal).unwrap();
 
     Terminal::reset()?;
     terminal.show_cursor()?;
@@ -57,7 +57,7 @@ impl StatefulWidget for &App {
                 item.style = Style::default().bg(Colors::ORANGE).fg(Colors::CHARCOAL);
             };
 
-            return (item, 1);
+            (item, 1)
         });
         let list = ListView::new(builder, 20);
 
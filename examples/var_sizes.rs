@@ -13,7 +13,7 @@ const SIZES: [u16; 19] = [32, 3, 4, 64, 6, 5, 4, 3, 3, 6, 5, 7, 3, 6, 9, 10, 4,
 fn main() -> Result<()> {
     let mut terminal = Terminal::init()?;
 
-    App::default().run(&mut terminal)?;
+    App.run(&mut terminal)?;
 
     Terminal::reset()?;
     terminal.show_cursor()?;
@@ -61,7 +61,7 @@ impl StatefulWidget for &App {
                 widget.line.style = widget.line.style.bg(Color::White);
             };
 
-            return (widget, size);
+            (widget, size)
         });
         let list = ListView::new(builder, item_count)
             .bg(Color::Black)
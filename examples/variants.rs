@@ -27,7 +27,7 @@ use tui_widget_list::ListState;
 
 fn main() -> Result<()> {
     let mut terminal = Terminal::init()?;
-    App::default().run(&mut terminal).unwrap();
+    App.run(&mut terminal).unwrap();
 
     Terminal::reset()?;
     terminal.show_cursor()?;
@@ -112,7 +112,7 @@ impl App {
             }
             return Ok(false);
         }
-        return Ok(false);
+        Ok(false)
     }
 }
 
@@ -126,7 +126,7 @@ impl StatefulWidget for &App {
 
         // Key mappings
         let [top_left, top_right] = Layout::horizontal([Min(0), Length(10)]).areas(top);
-        Controls::default().render(top_left, buf);
+        Controls.render(top_left, buf);
         state.fps_counter.render(top_right, buf);
 
         // Scroll config selection
@@ -1,27 +1,30 @@
 use crate::common::{item_container::ListItemContainer, Colors};
-use ratatui::{layout::Alignment, style::Stylize, text::Line, widgets::Padding};
-use tui_widget_list::{ListBuilder, ListView};
+use ratatui::{layout::Alignment, style::Style, text::Line, widgets::Padding};
+use tui_widget_list::{ListBuilder, ListStyles, ListView};
 
 pub(crate) struct PaddedListView;
 
 impl PaddedListView {
+    #[allow(clippy::new_ret_no_self)]
     pub(crate) fn new<'a>(
         infinite_scrolling: bool,
     ) -> ListView<'a, ListItemContainer<'a, Line<'a>>> {
         let builder = ListBuilder::new(|context| {
-            let mut line = ListItemContainer::new(
+            let line = ListItemContainer::new(
                 Line::from(format!("Item {0}", context.index)).alignment(Alignment::Center),
                 Padding::vertical(1),
             );
-            line = match context.is_selected {
-                true => line.bg(Colors::ORANGE).fg(Colors::CHARCOAL),
-                false if context.index % 2 == 0 => line.bg(Colors::CHARCOAL),
-                false => line.bg(Colors::BLACK),
-            };
 
-            return (line, 3);
+            (line, 3)
         });
 
-        return ListView::new(builder, 30).infinite_scrolling(infinite_scrolling);
+        let styles = ListStyles::default()
+            .even(Style::new().bg(Colors::CHARCOAL))
+            .odd(Style::new().bg(Colors::BLACK))
+            .selected(Style::new().bg(Colors::ORANGE).fg(Colors::CHARCOAL));
+
+        ListView::new(builder, 30)
+            .infinite_scrolling(infinite_scrolling)
+            .styles(styles)
     }
 }
@@ -42,6 +42,7 @@ impl std::fmt::Display for Variant {
 
 pub struct VariantsListView;
 impl VariantsListView {
+    #[allow(clippy::new_ret_no_self)]
     pub fn new<'a>() -> ListView<'a, ListItemContainer<'a, Line<'a>>> {
         let builder = ListBuilder::new(move |context| {
             let config = Variant::from_index(context.index);
@@ -52,10 +53,10 @@ impl VariantsListView {
                 item = item.bg(Colors::ORANGE).fg(Colors::CHARCOAL);
             };
 
-            return (item, 3);
+            (item, 3)
         });
 
-        return ListView::new(builder, Variant::COUNT);
+        ListView::new(builder, Variant::COUNT)
     }
 }
 
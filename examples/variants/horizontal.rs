@@ -5,6 +5,7 @@ use tui_widget_list::{ListBuilder, ListView, ScrollAxis};
 pub(crate) struct HorizontalListView;
 
 impl HorizontalListView {
+    #[allow(clippy::new_ret_no_self)]
     pub(crate) fn new<'a>() -> ListView<'a, ListItemContainer<'a, Line<'a>>> {
         let builder = ListBuilder::new(|context| {
             let mut line = ListItemContainer::new(
@@ -17,9 +18,9 @@ impl HorizontalListView {
                 false => line.bg(Colors::BLACK),
             };
 
-            return (line, 20);
+            (line, 20)
         });
 
-        return ListView::new(builder, 10).scroll_axis(ScrollAxis::Horizontal);
+        ListView::new(builder, 10).scroll_axis(ScrollAxis::Horizontal)
     }
 }
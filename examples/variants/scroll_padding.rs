@@ -5,6 +5,7 @@ use tui_widget_list::{ListBuilder, ListView};
 pub(crate) struct ScrollPaddingListView;
 
 impl ScrollPaddingListView {
+    #[allow(clippy::new_ret_no_self)]
     pub(crate) fn new<'a>() -> ListView<'a, ListItemContainer<'a, Line<'a>>> {
         let builder = ListBuilder::new(|context| {
             let mut line = ListItemContainer::new(
@@ -17,11 +18,11 @@ impl ScrollPaddingListView {
                 false => line.bg(Colors::BLACK),
             };
 
-            return (line, 3);
+            (line, 3)
         });
 
-        return ListView::new(builder, 30)
+        ListView::new(builder, 30)
             .infinite_scrolling(false)
-            .scroll_padding(5);
+            .scroll_padding(5)
     }
 }
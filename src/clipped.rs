@@ -0,0 +1,120 @@
+use std::cell::RefCell;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect, Size},
+    widgets::Widget,
+};
+
+thread_local! {
+    /// The buffer into which the default [`ClippedWidget::render_clipped`]
+    /// renders the whole widget. Kept between calls to avoid an allocation for
+    /// every truncated item.
+    static SCRATCH: RefCell<Buffer> = RefCell::new(Buffer::empty(Rect::ZERO));
+}
+
+/// A widget that can render only a part of itself.
+///
+/// Items that are partially visible are usually rendered fully into a hidden
+/// buffer, from which the visible part is copied. For large items, e.g. long
+/// paragraphs, this lays out a lot of content that is never shown. Item types
+/// that implement `ClippedWidget` render only their visible part instead, if
+/// the builder is created with [`ListBuilder::clipped`](crate::ListBuilder::clipped).
+///
+/// The default implementation falls back to rendering the whole widget into a
+/// scratch buffer that is reused between calls, and copies the visible part.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::prelude::*;
+/// use tui_widget_list::{Clip, ClippedWidget, ListBuilder};
+///
+/// struct Log(Vec<String>);
+///
+/// impl Widget for Log {
+///     fn render(self, area: Rect, buf: &mut Buffer) {
+///         let clip = Clip::full(area.as_size());
+///         self.render_clipped(area, clip, buf);
+///     }
+/// }
+///
+/// impl ClippedWidget for Log {
+///     fn render_clipped(self, area: Rect, clip: Clip, buf: &mut Buffer) {
+///         let lines = self.0.into_iter().skip(usize::from(clip.offset.y));
+///         for (y, line) in (area.top()..area.bottom()).zip(lines) {
+///             buf.set_stringn(area.x, y, line, usize::from(area.width), Style::new());
+///         }
+///     }
+/// }
+///
+/// let builder = ListBuilder::new(|context| {
+///     let lines: Vec<String> = (0..1000).map(|i| format!("{} {i}", context.index)).collect();
+///     (Log(lines), 1000)
+/// })
+/// .clipped();
+/// ```
+pub trait ClippedWidget: Widget {
+    /// Renders the part of the widget that is described by `clip` into `area`.
+    /// The size of `area` equals the size of the visible part.
+    /// The style of the list is already applied to `area`.
+    fn render_clipped(self, area: Rect, clip: Clip, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let area = area.intersection(buf.area);
+        let width = area
+            .width
+            .min(clip.full_size.width.saturating_sub(clip.offset.x));
+        let height = area
+            .height
+            .min(clip.full_size.height.saturating_sub(clip.offset.y));
+        if width == 0 || height == 0 {
+            return;
+        }
+        let len = usize::from(width);
+
+        SCRATCH.with(|scratch| {
+            let scratch = &mut *scratch.borrow_mut();
+            scratch.resize(Rect::from((Position::ORIGIN, clip.full_size)));
+            scratch.reset();
+
+            // The visible part starts out with the cells of `buf`, so that the
+            // style of the list is kept where the widget does not draw.
+            for y in 0..height {
+                let to = scratch.index_of(clip.offset.x, clip.offset.y + y);
+                let from = buf.index_of(area.x, area.y + y);
+                scratch.content[to..to + len].clone_from_slice(&buf.content[from..from + len]);
+            }
+            self.render(scratch.area, scratch);
+
+            for y in 0..height {
+                let to = buf.index_of(area.x, area.y + y);
+                let from = scratch.index_of(clip.offset.x, clip.offset.y + y);
+                buf.content[to..to + len].clone_from_slice(&scratch.content[from..from + len]);
+            }
+        });
+    }
+}
+
+/// The visible part of a partially visible item.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Clip {
+    /// The size of the whole item.
+    pub full_size: Size,
+
+    /// The position of the visible part, relative to the top left corner of
+    /// the item.
+    pub offset: Position,
+}
+
+impl Clip {
+    /// A clip that shows the whole item.
+    #[must_use]
+    pub fn full(full_size: Size) -> Self {
+        Self {
+            full_size,
+            offset: Position::ORIGIN,
+        }
+    }
+}
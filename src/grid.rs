@@ -0,0 +1,569 @@
+use std::{collections::HashMap, rc::Rc};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Rect, Size},
+    style::{Style, Styled},
+    widgets::{block::BlockExt, Block, StatefulWidget, Widget},
+};
+
+use crate::{
+    state::ScratchBuffer,
+    utils::{layout_on_viewport, ViewportElement},
+    view::{render_truncated, Truncation},
+    ListBuilder, ListState, ScrollAxis,
+};
+
+/// A struct representing a grid view.
+/// The widget displays a grid of items that is scrollable along both axes.
+/// Only the cells of the visible rows and columns are built.
+#[allow(clippy::module_name_repetitions)]
+pub struct GridView<'a, T> {
+    /// The total number of rows in the grid.
+    pub row_count: usize,
+
+    /// The total number of columns in the grid.
+    pub column_count: usize,
+
+    /// A `GridBuilder<T>` responsible for constructing the cells of the grid.
+    pub builder: GridBuilder<T>,
+
+    /// The base style of the grid view.
+    pub style: Style,
+
+    /// The base block surrounding the grid.
+    pub block: Option<Block<'a>>,
+}
+
+impl<'a, T> GridView<'a, T> {
+    /// Creates a new `GridView` with a builder, a row count and a column count.
+    #[must_use]
+    pub fn new(builder: GridBuilder<T>, row_count: usize, column_count: usize) -> Self {
+        Self {
+            row_count,
+            column_count,
+            builder,
+            style: Style::default(),
+            block: None,
+        }
+    }
+
+    /// Checks whether the grid is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.row_count == 0 || self.column_count == 0
+    }
+
+    /// Sets the block style that surrounds the whole grid.
+    #[must_use]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Set the base style of the grid.
+    #[must_use]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<T> Styled for GridView<'_, T> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(mut self, style: S) -> Self::Item {
+        self.style = style.into();
+        self
+    }
+}
+
+/// This structure holds information about the cell's position and selection
+/// status.
+pub struct GridBuildContext {
+    /// The row of the cell.
+    pub row: usize,
+
+    /// The column of the cell.
+    pub column: usize,
+
+    /// Indicates whether the cell is selected.
+    pub is_selected: bool,
+}
+
+/// A type alias for the closure.
+type GridBuilderClosure<T> = dyn Fn(&GridBuildContext) -> T;
+
+/// A type alias for the closure that measures a row or column.
+type GridMeasureClosure = dyn Fn(usize) -> u16;
+
+/// The builder for constructing the cells of a `GridView<T>`.
+pub struct GridBuilder<T> {
+    closure: Box<GridBuilderClosure<T>>,
+    row_height: Rc<GridMeasureClosure>,
+    column_width: Rc<GridMeasureClosure>,
+}
+
+impl<T> GridBuilder<T> {
+    /// Creates a new `GridBuilder` taking a closure as a parameter.
+    ///
+    /// The closure returns the widget of a cell and its size. All cells in a
+    /// row must have the same height, and all cells in a column the same width.
+    /// The height of a row is measured in the first column, the width of a
+    /// column in the first row. To measure rows and columns without building
+    /// cells, use [`GridBuilder::with_measure`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{layout::Size, text::Line};
+    /// use tui_widget_list::GridBuilder;
+    ///
+    /// let builder = GridBuilder::new(|context| {
+    ///     let text = format!("{0}:{1}", context.row, context.column);
+    ///     (Line::from(text), Size::new(8, 1))
+    /// });
+    /// ```
+    pub fn new<F>(closure: F) -> Self
+    where
+        F: Fn(&GridBuildContext) -> (T, Size) + 'static,
+    {
+        let closure = Rc::new(closure);
+        let row_height = {
+            let closure = Rc::clone(&closure);
+            move |row| {
+                let context = GridBuildContext {
+                    row,
+                    column: 0,
+                    is_selected: false,
+                };
+                closure(&context).1.height
+            }
+        };
+        let column_width = {
+            let closure = Rc::clone(&closure);
+            move |column| {
+                let context = GridBuildContext {
+                    row: 0,
+                    column,
+                    is_selected: false,
+                };
+                closure(&context).1.width
+            }
+        };
+
+        GridBuilder::with_measure(row_height, column_width, move |context| closure(context).0)
+    }
+
+    /// Creates a new `GridBuilder` with separate closures to measure the rows
+    /// and columns and to build the cells.
+    ///
+    /// The grid needs the height of every row and the width of every column it
+    /// lays out, including those that are scrolled past. With `GridBuilder::new`
+    /// a cell is constructed for each of them just to learn its size. Here, the
+    /// cheap `row_height` and `column_width` closures are used instead, and
+    /// `build` is only called for cells that are rendered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::text::Line;
+    /// use tui_widget_list::GridBuilder;
+    ///
+    /// let builder = GridBuilder::with_measure(
+    ///     |_row| 1,
+    ///     |column| if column == 0 { 4 } else { 8 },
+    ///     |context| Line::from(format!("{0}:{1}", context.row, context.column)),
+    /// );
+    /// ```
+    pub fn with_measure<R, C, F>(row_height: R, column_width: C, build: F) -> Self
+    where
+        R: Fn(usize) -> u16 + 'static,
+        C: Fn(usize) -> u16 + 'static,
+        F: Fn(&GridBuildContext) -> T + 'static,
+    {
+        GridBuilder {
+            closure: Box::new(build),
+            row_height: Rc::new(row_height),
+            column_width: Rc::new(column_width),
+        }
+    }
+
+    /// Method to call the stored closure.
+    pub(crate) fn call_closure(&self, context: &GridBuildContext) -> T {
+        (self.closure)(context)
+    }
+
+    /// Returns a `ListBuilder` that lays out the rows or the columns of the grid.
+    fn axis_builder(&self, scroll_axis: ScrollAxis) -> ListBuilder<()> {
+        let measure = match scroll_axis {
+            ScrollAxis::Vertical => Rc::clone(&self.row_height),
+            ScrollAxis::Horizontal => Rc::clone(&self.column_width),
+        };
+        ListBuilder::new(move |context| ((), measure(context.index)))
+    }
+}
+
+/// The state of a [`GridView`].
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Default)]
+pub struct GridState {
+    /// The selected cell as `(row, column)`. If `None`, no cell is selected.
+    pub selected: Option<(usize, usize)>,
+
+    /// The state of the rows.
+    pub(crate) rows: ListState,
+
+    /// The state of the columns.
+    pub(crate) columns: ListState,
+
+    /// The buffer into which truncated cells are rendered. Kept between
+    /// renders to avoid an allocation for every truncated cell.
+    pub(crate) scratch: ScratchBuffer,
+}
+
+impl GridState {
+    /// Selects a cell by its row and column.
+    pub fn select(&mut self, cell: Option<(usize, usize)>) {
+        self.selected = cell;
+        if cell.is_none() {
+            self.rows.select(None);
+            self.columns.select(None);
+        }
+    }
+
+    /// Selects the cell in the next row. Selects the first cell if none is
+    /// selected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_widget_list::GridState;
+    ///
+    /// let mut grid_state = GridState::default();
+    /// grid_state.next_row();
+    /// ```
+    pub fn next_row(&mut self) {
+        self.move_selection(|(row, column), rows, _| ((row + 1).min(rows - 1), column));
+    }
+
+    /// Selects the cell in the previous row. Selects the first cell if none is
+    /// selected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_widget_list::GridState;
+    ///
+    /// let mut grid_state = GridState::default();
+    /// grid_state.previous_row();
+    /// ```
+    pub fn previous_row(&mut self) {
+        self.move_selection(|(row, column), _, _| (row.saturating_sub(1), column));
+    }
+
+    /// Selects the cell in the next column. Selects the first cell if none is
+    /// selected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_widget_list::GridState;
+    ///
+    /// let mut grid_state = GridState::default();
+    /// grid_state.next_column();
+    /// ```
+    pub fn next_column(&mut self) {
+        self.move_selection(|(row, column), _, columns| (row, (column + 1).min(columns - 1)));
+    }
+
+    /// Selects the cell in the previous column. Selects the first cell if none
+    /// is selected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_widget_list::GridState;
+    ///
+    /// let mut grid_state = GridState::default();
+    /// grid_state.previous_column();
+    /// ```
+    pub fn previous_column(&mut self) {
+        self.move_selection(|(row, column), _, _| (row, column.saturating_sub(1)));
+    }
+
+    /// Moves the selection with `f`, which receives the selected cell, the
+    /// number of rows and the number of columns.
+    fn move_selection<F>(&mut self, f: F)
+    where
+        F: FnOnce((usize, usize), usize, usize) -> (usize, usize),
+    {
+        let (rows, columns) = (self.rows.num_elements, self.columns.num_elements);
+        if rows == 0 || columns == 0 {
+            return;
+        }
+        let cell = match self.selected {
+            Some(cell) => f(cell, rows, columns),
+            None => (0, 0),
+        };
+        self.select(Some(cell));
+    }
+}
+
+impl<T: Widget> StatefulWidget for GridView<'_, T> {
+    type State = GridState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.rows.set_num_elements(self.row_count);
+        state.columns.set_num_elements(self.column_count);
+
+        // Set the base style
+        buf.set_style(area, self.style);
+
+        // Set the base block
+        self.block.render(area, buf);
+        let area = self.block.inner_if_some(area);
+
+        // Grid is empty
+        if self.is_empty() || area.is_empty() {
+            return;
+        }
+
+        // Each axis is laid out like a list.
+        let selected = state.selected.map(|(row, column)| {
+            (
+                row.min(self.row_count - 1),
+                column.min(self.column_count - 1),
+            )
+        });
+        state.selected = selected;
+        state.rows.selected = selected.map(|(row, _)| row);
+        state.columns.selected = selected.map(|(_, column)| column);
+
+        let rows = layout_axis(
+            &mut state.rows,
+            &self.builder.axis_builder(ScrollAxis::Vertical),
+            self.row_count,
+            area.height,
+            area.width,
+            ScrollAxis::Vertical,
+        );
+        let columns = layout_axis(
+            &mut state.columns,
+            &self.builder.axis_builder(ScrollAxis::Horizontal),
+            self.column_count,
+            area.width,
+            area.height,
+            ScrollAxis::Horizontal,
+        );
+
+        for (row, row_element, y) in &rows {
+            for (column, column_element, x) in &columns {
+                let context = GridBuildContext {
+                    row: *row,
+                    column: *column,
+                    is_selected: selected == Some((*row, *column)),
+                };
+                let widget = self.builder.call_closure(&context);
+                render_cell(
+                    widget,
+                    area,
+                    buf,
+                    (column_element, area.x + x),
+                    (row_element, area.y + y),
+                    self.style,
+                    &mut state.scratch.0,
+                );
+            }
+        }
+    }
+}
+
+/// Lays out the rows or the columns of the grid.
+///
+/// Returns the visible items in order together with their position.
+fn layout_axis(
+    state: &mut ListState,
+    builder: &ListBuilder<()>,
+    item_count: usize,
+    total_main_axis_size: u16,
+    cross_axis_size: u16,
+    scroll_axis: ScrollAxis,
+) -> Vec<(usize, ViewportElement<()>, u16)> {
+    let mut viewport: HashMap<usize, ViewportElement<()>> = layout_on_viewport(
+        state,
+        builder,
+        0..item_count,
+        total_main_axis_size,
+        cross_axis_size,
+        scroll_axis,
+        0,
+    );
+
+    let mut pos = 0;
+    let mut elements = Vec::with_capacity(viewport.len());
+    for index in state.view_state.offset..state.view_state.offset + viewport.len() {
+        let Some(element) = viewport.remove(&index) else {
+            break;
+        };
+        let size = element.main_axis_size - element.truncation.value();
+        elements.push((index, element, pos));
+        pos += size;
+    }
+    elements
+}
+
+/// Renders a cell that may be truncated along both axes. Truncated cells are
+/// rendered like truncated list items: the rows are the main axis, the columns
+/// are scrolled along the cross axis.
+fn render_cell<T: Widget>(
+    widget: T,
+    area: Rect,
+    buf: &mut Buffer,
+    (column, x): (&ViewportElement<()>, u16),
+    (row, y): (&ViewportElement<()>, u16),
+    base_style: Style,
+    scratch: &mut Buffer,
+) {
+    let visible = Rect::new(
+        x,
+        y,
+        column.main_axis_size - column.truncation.value(),
+        row.main_axis_size - row.truncation.value(),
+    )
+    .intersection(area);
+
+    if column.truncation == Truncation::None && row.truncation == Truncation::None {
+        widget.render(visible, buf);
+        return;
+    }
+
+    let skip_x = match column.truncation {
+        Truncation::Top(value) => value,
+        _ => 0,
+    };
+    let available_area = Rect {
+        width: column.main_axis_size,
+        ..visible
+    };
+    render_truncated(
+        widget,
+        available_area,
+        buf,
+        row.main_axis_size,
+        &row.truncation,
+        base_style,
+        ScrollAxis::Vertical,
+        skip_x,
+        visible,
+        scratch,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::Cell;
+
+    use ratatui::text::{Line, Text};
+
+    use super::*;
+
+    fn grid() -> GridView<'static, Line<'static>> {
+        let builder = GridBuilder::new(|context| {
+            let text = format!("{0}{1}", context.row, context.column);
+            let width = if context.column == 0 { 3 } else { 2 };
+            (Line::from(text), Size::new(width, 1))
+        });
+        GridView::new(builder, 100, 100)
+    }
+
+    #[test]
+    fn render_grid() {
+        // given
+        let area = Rect::new(0, 0, 7, 2);
+        let mut buf = Buffer::empty(area);
+        let mut state = GridState::default();
+
+        // when
+        grid().render(area, &mut buf, &mut state);
+
+        // then
+        assert_eq!(buf, Buffer::with_lines(vec!["00 0102", "10 1112"]));
+    }
+
+    #[test]
+    fn scroll_grid() {
+        // given
+        let area = Rect::new(0, 0, 5, 2);
+        let mut buf = Buffer::empty(area);
+        let mut state = GridState::default();
+        grid().render(area, &mut buf, &mut state);
+
+        // when
+        state.select(Some((4, 3)));
+        let mut buf = Buffer::empty(area);
+        grid().render(area, &mut buf, &mut state);
+
+        // then
+        assert_eq!(buf, Buffer::with_lines(vec!["13233", "14243"]));
+    }
+
+    #[test]
+    fn truncated_cells_reuse_scratch_buffer() {
+        // given
+        let area = Rect::new(0, 0, 3, 3);
+        let mut state = GridState::default();
+        let grid = || {
+            let builder = GridBuilder::new(|context| {
+                let lines = vec![
+                    Line::from(format!("{0}{1}", context.row, context.column)),
+                    Line::from(format!("x{0}", context.column)),
+                ];
+                (Text::from(lines), Size::new(2, 2))
+            });
+            GridView::new(builder, 2, 2)
+        };
+
+        // when
+        state.select(Some((1, 1)));
+        let mut buf = Buffer::empty(area);
+        grid().render(area, &mut buf, &mut state);
+
+        // then
+        assert_eq!(buf, Buffer::with_lines(vec!["0x1", "011", "0x1"]));
+        assert_eq!(state.scratch.0.area, Rect::new(0, 1, 2, 2));
+    }
+
+    #[test]
+    fn build_only_visible_cells() {
+        // given
+        let area = Rect::new(0, 0, 5, 2);
+        let mut state = GridState::default();
+        let built = Rc::new(Cell::new(0));
+        let grid = || {
+            let built = Rc::clone(&built);
+            let builder = GridBuilder::with_measure(
+                |_| 1,
+                |_| 2,
+                move |context| {
+                    built.set(built.get() + 1);
+                    Line::from(format!("{0}{1}", context.row % 10, context.column % 10))
+                },
+            );
+            GridView::new(builder, 100, 100)
+        };
+
+        // when
+        state.select(Some((50, 50)));
+        let mut buf = Buffer::empty(area);
+        grid().render(area, &mut buf, &mut state);
+
+        // then
+        assert_eq!(buf, Buffer::with_lines(vec!["89990", "80900"]));
+        assert_eq!(built.get(), 6);
+    }
+}
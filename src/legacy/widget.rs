@@ -264,11 +264,10 @@ mod test {
 
     impl PreRender for TestItem {
         fn pre_render(&mut self, context: &PreRenderContext) -> u16 {
-            let main_axis_size = match context.scroll_axis {
+            match context.scroll_axis {
                 ScrollAxis::Vertical => 3,
                 ScrollAxis::Horizontal => 3,
-            };
-            main_axis_size
+            }
         }
     }
 
@@ -8,6 +8,7 @@
 //!
 //! This crate provides a stateful widget [`ListView`] implementation for `Ratatui`. The associated [`ListState`], offers functionalities such as navigating to the next and previous items.
 //! The list view support both horizontal and vertical scrolling.
+//! For data that is virtualized along both axes, e.g. spreadsheets, the crate provides a [`GridView`] with an associated [`GridState`].
 //!
 //! ## Configuration
 //! The [`ListView`] can be customized with the following options:
@@ -15,6 +16,24 @@
 //!
 //! - [`ListView::scroll_padding`]: Specifies whether content should remain visible while scrolling, ensuring that a specified amount of padding is preserved above/below the selected item during scrolling.
 //! - [`ListView::infinite_scrolling`]: Allows the list to wrap around when scrolling past the first or last element.
+//! - [`ListView::reverse`]: Lays out the list in reverse, anchoring the first item at the bottom (or right) of the viewport.
+//! - [`ListView::align`]: Aligns the items along the main axis if they do not fill the viewport, e.g. centered or spaced evenly.
+//! - [`ListView::cross_axis_alignment`]: Aligns items that are narrower than the list along the cross axis, see [`ListBuilder::with_cross_axis_size`].
+//! - [`ListView::snap`]: Shows only whole items on the viewport and scrolls by entire items.
+//! - [`ListView::carousel`]: Keeps the selected item centered on the viewport, like a wheel picker.
+//! - [`ListView::pinned_leading`], [`ListView::pinned_trailing`]: Pins items at the start or end of the list, so they stay visible while the other items scroll.
+//! - [`ListView::fixed_item_size`]: Sets the same size for all items, which keeps layouting fast for lists with millions of items.
+//! - [`ListView::estimated_item_size`]: Estimates the size of items that have not been laid out yet, so that jumping through long lists does not measure every item.
+//! - [`ListView::virtual_cross_axis_size`]: Lets items be wider than the list. They are scrolled along the cross axis with [`ListState::scroll_left`] and [`ListState::scroll_right`].
+//! - [`ListView::masonry`]: Lays out the items in multiple columns, each item is placed in the currently shortest column.
+//! - [`ListView::scrollbar`]: Shows a scrollbar inside the block, whose position and length are measured in cells.
+//! - [`ListView::overflow_indicators`]: Shows markers such as `▲ 12 more` if items are hidden before or after the viewport.
+//! - [`ListView::empty_placeholder`]: Renders a placeholder, e.g. "No results", if the list has no items.
+//! - [`ListView::highlight_symbol`], [`ListView::highlight_style`], [`ListView::highlight_spacing`]: Draws a symbol next to the selected item and patches it with a style, like ratatui's `List`.
+//! - [`ListView::styles`]: Patches styles for even, odd, selected, hovered and disabled items onto the rendered items.
+//! - [`ListView::loading_placeholder`]: Renders placeholders, e.g. skeleton rows, for items whose data is not ready yet.
+//! - [`ListView::item_block`]: Wraps each item in a block, e.g. a card with borders, and adds the thickness of the block to the size of the item.
+//! - [`ListView::truncation_marker`]: Dims the cut edge of partially visible items or draws a marker such as `…` on it.
 //! - [`ListView::style`]: Defines the base style of the list.
 //! - [`ListView::block`]: Optional outer block surrounding the list.
 //!
@@ -96,13 +115,26 @@
 //! ### Infinite scrolling, scroll padding, horizontal scrolling
 //!
 //!![](examples/tapes/variants.gif?v=1)
+pub(crate) mod clipped;
+pub(crate) mod grid;
 pub(crate) mod legacy;
+pub(crate) mod masonry;
+pub(crate) mod overflow;
+pub(crate) mod size_index;
 pub(crate) mod state;
+pub(crate) mod styles;
 pub(crate) mod utils;
 pub(crate) mod view;
 
+pub use clipped::{Clip, ClippedWidget};
+pub use grid::{GridBuildContext, GridBuilder, GridState, GridView};
+pub use overflow::{OverflowIndicators, OverflowPlacement, TruncationMarker};
 pub use state::ListState;
-pub use view::{ListBuildContext, ListBuilder, ListView, ScrollAxis};
+pub use styles::ListStyles;
+pub use view::{
+    CrossAxisAlignment, ItemLayout, ListBuildContext, ListBuilder, ListView, MainAxisAlignment,
+    ScrollAxis,
+};
 
 #[allow(deprecated)]
 pub use legacy::{
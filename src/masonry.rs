@@ -0,0 +1,137 @@
+/// The cached placement of the items in masonry mode.
+///
+/// Each item is placed in the column that is currently the shortest. Since the
+/// shortest column never gets shorter, the start positions of the items do
+/// not decrease with their index. Items are placed lazily, only as far as
+/// needed to fill the viewport.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MasonryLayout {
+    /// The number of columns.
+    columns: usize,
+
+    /// The width of a column.
+    column_width: u16,
+
+    /// The placement of the items, in index order.
+    pub(crate) placements: Vec<Placement>,
+
+    /// The end position of each column.
+    column_ends: Vec<u64>,
+
+    /// The position of the viewport.
+    pub(crate) scroll: u64,
+}
+
+/// The placement of an item in masonry mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Placement {
+    /// The column of the item.
+    pub(crate) column: usize,
+
+    /// The start position of the item along the main axis.
+    pub(crate) start: u64,
+
+    /// The size of the item along the main axis.
+    pub(crate) size: u16,
+}
+
+impl Placement {
+    /// The end position of the item along the main axis.
+    pub(crate) fn end(&self) -> u64 {
+        self.start + u64::from(self.size)
+    }
+}
+
+impl MasonryLayout {
+    /// Prepares the layout for the given columns and items. The placements are
+    /// discarded if the columns changed, and cut if there are fewer items.
+    pub(crate) fn prepare(&mut self, columns: usize, column_width: u16, item_count: usize) {
+        if self.columns != columns || self.column_width != column_width {
+            self.columns = columns;
+            self.column_width = column_width;
+            self.truncate(0);
+        }
+        if self.placements.len() > item_count {
+            self.truncate(item_count);
+        }
+    }
+
+    /// Removes the placements of the items from `len` onwards.
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.placements.truncate(len);
+        self.column_ends = vec![0; self.columns];
+        for placement in &self.placements {
+            self.column_ends[placement.column] = placement.end();
+        }
+    }
+
+    /// Places the next item in the shortest column.
+    pub(crate) fn place(&mut self, size: u16) {
+        let Some((column, &start)) = self
+            .column_ends
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, &end)| end)
+        else {
+            return;
+        };
+        self.placements.push(Placement {
+            column,
+            start,
+            size,
+        });
+        self.column_ends[column] = start + u64::from(size);
+    }
+
+    /// Whether every column has an item that starts at or after `position`,
+    /// i.e. items that are placed from now on start after `position`.
+    pub(crate) fn is_filled_to(&self, position: u64) -> bool {
+        let mut filled = vec![false; self.columns];
+        for placement in self.placements.iter().rev() {
+            if placement.start < position {
+                break;
+            }
+            filled[placement.column] = true;
+        }
+        filled.into_iter().all(|filled| filled)
+    }
+
+    /// Returns the next item in the same column as `index`.
+    pub(crate) fn next_in_column(&self, index: usize) -> Option<usize> {
+        let column = self.placements.get(index)?.column;
+        (index + 1..self.placements.len()).find(|&i| self.placements[i].column == column)
+    }
+
+    /// Returns the previous item in the same column as `index`.
+    pub(crate) fn previous_in_column(&self, index: usize) -> Option<usize> {
+        let column = self.placements.get(index)?.column;
+        (0..index)
+            .rev()
+            .find(|&i| self.placements[i].column == column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn place_in_shortest_column() {
+        // given
+        let mut layout = MasonryLayout::default();
+        layout.prepare(2, 5, 5);
+
+        // when
+        for size in [3, 1, 1, 2, 1] {
+            layout.place(size);
+        }
+
+        // then
+        let columns: Vec<usize> = layout.placements.iter().map(|p| p.column).collect();
+        assert_eq!(columns, vec![0, 1, 1, 1, 0]);
+        assert_eq!(layout.placements[4].start, 3);
+        assert_eq!(layout.next_in_column(1), Some(2));
+        assert_eq!(layout.previous_in_column(4), Some(0));
+        assert_eq!(layout.next_in_column(4), None);
+    }
+}
@@ -0,0 +1,276 @@
+use ratatui::{
+    buffer::{Buffer, Cell},
+    layout::Rect,
+    style::{Modifier, Style, Styled},
+    text::Line,
+    widgets::Widget,
+};
+
+use crate::ScrollAxis;
+
+/// Markers that indicate that there are items before or after the viewport,
+/// such as `▲ 12 more` or `▼ 30 more`.
+///
+/// For vertical lists the markers are drawn at the top and the bottom, for
+/// horizontal lists at the left and the right, written from top to bottom.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::style::{Style, Stylize};
+/// use tui_widget_list::{ListBuilder, ListView, OverflowIndicators};
+///
+/// let builder = ListBuilder::new(|context| (format!("Item {0}", context.index), 1));
+/// let indicators = OverflowIndicators::default().style(Style::new().dim());
+/// let list = ListView::new(builder, 20).overflow_indicators(indicators);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OverflowIndicators {
+    /// The symbols before and after the viewport. Arrows if `None`.
+    pub(crate) symbols: Option<(String, String)>,
+
+    /// The style of the markers.
+    pub(crate) style: Style,
+
+    /// Where the markers are drawn.
+    pub(crate) placement: OverflowPlacement,
+}
+
+/// Where the [`OverflowIndicators`] are drawn.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverflowPlacement {
+    /// The markers are drawn on the border of the block. Without a block they
+    /// are drawn over the first and last row (or column) of the list.
+    ///
+    /// On the border, the markers do not overlap the titles of the block. They
+    /// are centered if possible, otherwise moved to the end or the start of
+    /// the border. If there is no free space, the cells of the titles are
+    /// skipped.
+    #[default]
+    Border,
+    /// The first and last row (or column) of the list are reserved for the
+    /// markers.
+    Inside,
+}
+
+impl OverflowIndicators {
+    /// Sets the symbols that are shown before the number of hidden items.
+    /// Defaults to `▲`/`▼` for vertical and `◀`/`▶` for horizontal lists.
+    #[must_use]
+    pub fn symbols<S: Into<String>>(mut self, before: S, after: S) -> Self {
+        self.symbols = Some((before.into(), after.into()));
+        self
+    }
+
+    /// Sets the style of the markers.
+    #[must_use]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets where the markers are drawn.
+    #[must_use]
+    pub fn placement(mut self, placement: OverflowPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Returns the area of the list, with the space for the markers removed if
+    /// they are drawn inside.
+    pub(crate) fn list_area(&self, area: Rect, scroll_axis: ScrollAxis) -> Rect {
+        if self.placement == OverflowPlacement::Border {
+            return area;
+        }
+        match scroll_axis {
+            ScrollAxis::Vertical => Rect {
+                y: area.y + area.height.min(1),
+                height: area.height.saturating_sub(2),
+                ..area
+            },
+            ScrollAxis::Horizontal => Rect {
+                x: area.x + area.width.min(1),
+                width: area.width.saturating_sub(2),
+                ..area
+            },
+        }
+    }
+
+    /// Draws the markers for the hidden items at the start and the end of
+    /// `area`. Nothing is drawn for a count of zero. If `on_border` is true,
+    /// `area` is the area of a block and the markers avoid its titles.
+    pub(crate) fn render(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        scroll_axis: ScrollAxis,
+        (count_start, count_end): (usize, usize),
+        on_border: bool,
+    ) {
+        let (before, after) = match (&self.symbols, scroll_axis) {
+            (Some((before, after)), _) => (before.as_str(), after.as_str()),
+            (None, ScrollAxis::Vertical) => ("▲", "▼"),
+            (None, ScrollAxis::Horizontal) => ("◀", "▶"),
+        };
+        if area.is_empty() {
+            return;
+        }
+
+        for (symbol, count, at_end) in [(before, count_start, false), (after, count_end, true)] {
+            if count == 0 {
+                continue;
+            }
+            let text = format!("{symbol} {count} more");
+            match scroll_axis {
+                ScrollAxis::Vertical => {
+                    let y = if at_end {
+                        area.bottom() - 1
+                    } else {
+                        area.top()
+                    };
+                    let row = Rect {
+                        y,
+                        height: 1,
+                        ..area
+                    };
+                    if on_border {
+                        self.render_on_border(&text, row, buf);
+                    } else {
+                        Line::from(text)
+                            .set_style(self.style)
+                            .centered()
+                            .render(row, buf);
+                    }
+                }
+                ScrollAxis::Horizontal => {
+                    let x = if at_end {
+                        area.right() - 1
+                    } else {
+                        area.left()
+                    };
+                    let column = Rect {
+                        x,
+                        width: 1,
+                        ..area
+                    };
+                    for (y, c) in (column.top()..column.bottom()).zip(text.chars()) {
+                        let cell = &mut buf[(x, y)];
+                        if !on_border || is_free(cell) {
+                            cell.set_char(c).set_style(self.style);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl OverflowIndicators {
+    /// Draws a marker into the top or bottom border `row` of a block, such
+    /// that it does not overlap the titles of the block. The corners of the
+    /// border are kept.
+    fn render_on_border(&self, text: &str, row: Rect, buf: &mut Buffer) {
+        let span = Rect {
+            x: row.x + row.width.min(1),
+            width: row.width.saturating_sub(2),
+            ..row
+        };
+        let width = u16::try_from(text.chars().count()).unwrap_or(u16::MAX);
+        let fits =
+            |x: u16| width <= span.width && (x..x + width).all(|x| is_free(&buf[(x, row.y)]));
+        let centered = span.x + span.width.saturating_sub(width) / 2;
+        let end = span.right().saturating_sub(width).max(span.x);
+        let position = [centered, end, span.x].into_iter().find(|x| fits(*x));
+
+        let (start, skip_titles) = position.map_or((centered, true), |x| (x, false));
+        for (x, c) in (start..span.right()).zip(text.chars()) {
+            let cell = &mut buf[(x, row.y)];
+            if !skip_titles || is_free(cell) {
+                cell.set_char(c).set_style(self.style);
+            }
+        }
+    }
+}
+
+/// Whether a cell on the border of a block holds no title text, i.e. it is
+/// empty or holds a border symbol.
+fn is_free(cell: &Cell) -> bool {
+    cell.symbol()
+        .chars()
+        .all(|c| c.is_whitespace() || ('\u{2500}'..='\u{259F}').contains(&c))
+}
+
+/// Marks the cut edge of items that are only partially visible, so that it is
+/// clear that the item continues beyond the viewport.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::style::{Style, Stylize};
+/// use tui_widget_list::{ListBuilder, ListView, TruncationMarker};
+///
+/// let builder = ListBuilder::new(|context| (format!("Item {0}", context.index), 3));
+/// let marker = TruncationMarker::Symbol("…".to_string(), Style::new().dim());
+/// let list = ListView::new(builder, 20).truncation_marker(marker);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TruncationMarker {
+    /// Patches the style onto the row (or column) at the cut edge, e.g. to
+    /// dim it.
+    Fade(Style),
+    /// Draws the symbol in the middle of the row (or column) at the cut edge.
+    Symbol(String, Style),
+}
+
+impl Default for TruncationMarker {
+    /// Dims the cut edge.
+    fn default() -> Self {
+        Self::Fade(Style::new().add_modifier(Modifier::DIM))
+    }
+}
+
+impl TruncationMarker {
+    /// Marks the edge of the visible `area` of an item. The edge is the start
+    /// of `area` along the scroll axis, or the end if `at_end` is true.
+    pub(crate) fn render(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        scroll_axis: ScrollAxis,
+        at_end: bool,
+    ) {
+        if area.is_empty() {
+            return;
+        }
+        let edge = match (scroll_axis, at_end) {
+            (ScrollAxis::Vertical, false) => Rect { height: 1, ..area },
+            (ScrollAxis::Vertical, true) => Rect {
+                y: area.bottom() - 1,
+                height: 1,
+                ..area
+            },
+            (ScrollAxis::Horizontal, false) => Rect { width: 1, ..area },
+            (ScrollAxis::Horizontal, true) => Rect {
+                x: area.right() - 1,
+                width: 1,
+                ..area
+            },
+        };
+
+        match self {
+            Self::Fade(style) => buf.set_style(edge, *style),
+            Self::Symbol(symbol, style) => match scroll_axis {
+                ScrollAxis::Vertical => {
+                    Line::from(symbol.as_str())
+                        .set_style(*style)
+                        .centered()
+                        .render(edge, buf);
+                }
+                ScrollAxis::Horizontal => {
+                    let y = edge.y + edge.height / 2;
+                    buf.set_stringn(edge.x, y, symbol, 1, *style);
+                }
+            },
+        }
+    }
+}
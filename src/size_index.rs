@@ -0,0 +1,242 @@
+use std::ops::Range;
+
+/// A cached index of the sizes of the items along the main axis.
+///
+/// The sizes are stored in a Fenwick tree, so that the position of an item
+/// and the item at a position can be found in O(log n). It is used by
+/// [`ListState::scroll_to_fraction`](crate::ListState::scroll_to_fraction) and the scrollbar, the layout of the
+/// viewport does not use it. The index is rebuilt
+/// if the number of items or the size along the cross axis changes. Single
+/// items can be invalidated, they are measured again on the next update.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SizeIndex {
+    /// The size of each item.
+    sizes: Vec<u16>,
+
+    /// The Fenwick tree, `tree[i]` holds the sum of the sizes in
+    /// `(i - lowbit(i))..i`. The first entry is unused.
+    tree: Vec<u64>,
+
+    /// The items that must be measured again.
+    invalidated: Vec<Range<usize>>,
+
+    /// The size along the cross axis with which the items were measured.
+    cross_axis_size: u16,
+}
+
+impl SizeIndex {
+    /// Marks the items in `range` to be measured again on the next update.
+    pub(crate) fn invalidate(&mut self, range: Range<usize>) {
+        if !range.is_empty() {
+            self.invalidated.push(range);
+        }
+    }
+
+    /// Brings the index up to date. If the number of items or the size along
+    /// the cross axis changed, all items are measured, otherwise only the
+    /// invalidated items.
+    pub(crate) fn update<F>(&mut self, item_count: usize, cross_axis_size: u16, mut measure: F)
+    where
+        F: FnMut(usize) -> u16,
+    {
+        if self.sizes.len() != item_count || self.cross_axis_size != cross_axis_size {
+            self.cross_axis_size = cross_axis_size;
+            self.sizes = (0..item_count).map(&mut measure).collect();
+            self.invalidated.clear();
+            self.build();
+            return;
+        }
+
+        for range in std::mem::take(&mut self.invalidated) {
+            for index in range.start..range.end.min(item_count) {
+                self.set(index, measure(index));
+            }
+        }
+    }
+
+    /// Brings the index up to date without measuring items. New and invalidated
+    /// items are assumed to have the `estimated` size until their actual size
+    /// is set. If the number of items changed, the sizes of the items that are
+    /// still present are kept. If the size along the cross axis changed, all
+    /// items are assumed to have the `estimated` size again.
+    pub(crate) fn update_estimated(
+        &mut self,
+        item_count: usize,
+        cross_axis_size: u16,
+        estimated: u16,
+    ) {
+        if self.cross_axis_size != cross_axis_size {
+            self.cross_axis_size = cross_axis_size;
+            self.sizes.clear();
+        }
+        if self.sizes.len() != item_count {
+            self.sizes.resize(item_count, estimated);
+            self.build();
+        }
+
+        for range in std::mem::take(&mut self.invalidated) {
+            for index in range.start..range.end.min(item_count) {
+                self.set(index, estimated);
+            }
+        }
+    }
+
+    /// Returns the number of items in the index.
+    pub(crate) fn len(&self) -> usize {
+        self.sizes.len()
+    }
+
+    /// Builds the Fenwick tree from the sizes in O(n).
+    fn build(&mut self) {
+        let len = self.sizes.len();
+        self.tree = vec![0; len + 1];
+        for i in 1..=len {
+            self.tree[i] += u64::from(self.sizes[i - 1]);
+            let parent = i + lowbit(i);
+            if parent <= len {
+                self.tree[parent] += self.tree[i];
+            }
+        }
+    }
+
+    /// Sets the size of an item.
+    pub(crate) fn set(&mut self, index: usize, size: u16) {
+        let Some(old_size) = self.sizes.get_mut(index) else {
+            return;
+        };
+        let (old, new) = (u64::from(*old_size), u64::from(size));
+        *old_size = size;
+
+        let mut i = index + 1;
+        while i < self.tree.len() {
+            self.tree[i] = self.tree[i] + new - old;
+            i += lowbit(i);
+        }
+    }
+
+    /// Returns the total size of the items before `index`.
+    pub(crate) fn prefix_sum(&self, index: usize) -> u64 {
+        let mut sum = 0;
+        let mut i = index.min(self.sizes.len());
+        while i > 0 {
+            sum += self.tree[i];
+            i -= lowbit(i);
+        }
+        sum
+    }
+
+    /// Returns the total size of all items.
+    pub(crate) fn total(&self) -> u64 {
+        self.prefix_sum(self.sizes.len())
+    }
+
+    /// Returns the item that covers `position`, together with the distance of
+    /// `position` from the start of the item. Positions beyond the end of the
+    /// list map to the last item.
+    pub(crate) fn find(&self, position: u64) -> Option<(usize, u16)> {
+        let len = self.sizes.len();
+        if len == 0 {
+            return None;
+        }
+
+        // Find the largest number of items whose total size is <= position.
+        let mut index = 0;
+        let mut remaining = position;
+        let mut step = len.next_power_of_two();
+        while step > 0 {
+            let next = index + step;
+            if next <= len && self.tree[next] <= remaining {
+                index = next;
+                remaining -= self.tree[next];
+            }
+            step /= 2;
+        }
+
+        if index >= len {
+            return Some((len - 1, self.sizes[len - 1].saturating_sub(1)));
+        }
+        let within = u16::try_from(remaining).unwrap_or(u16::MAX);
+        Some((index, within))
+    }
+}
+
+/// Returns the lowest set bit of `i`.
+fn lowbit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(sizes: &[u16]) -> SizeIndex {
+        let mut index = SizeIndex::default();
+        index.update(sizes.len(), 1, |i| sizes[i]);
+        index
+    }
+
+    #[test]
+    fn prefix_sum() {
+        let index = index(&[2, 3, 1, 4, 5]);
+
+        assert_eq!(index.prefix_sum(0), 0);
+        assert_eq!(index.prefix_sum(1), 2);
+        assert_eq!(index.prefix_sum(3), 6);
+        assert_eq!(index.prefix_sum(5), 15);
+        assert_eq!(index.total(), 15);
+    }
+
+    #[test]
+    fn find() {
+        let index = index(&[2, 3, 1, 4, 5]);
+
+        assert_eq!(index.find(0), Some((0, 0)));
+        assert_eq!(index.find(1), Some((0, 1)));
+        assert_eq!(index.find(2), Some((1, 0)));
+        assert_eq!(index.find(5), Some((2, 0)));
+        assert_eq!(index.find(14), Some((4, 4)));
+        assert_eq!(index.find(100), Some((4, 4)));
+        assert_eq!(SizeIndex::default().find(0), None);
+    }
+
+    #[test]
+    fn invalidate() {
+        let mut sizes = vec![2, 3, 1, 4, 5];
+        let mut index = index(&sizes);
+
+        sizes[1] = 1;
+        sizes[3] = 6;
+        index.invalidate(1..4);
+        index.update(sizes.len(), 1, |i| sizes[i]);
+
+        assert_eq!(index.prefix_sum(2), 3);
+        assert_eq!(index.total(), 15);
+        assert_eq!(index.find(4), Some((3, 0)));
+    }
+
+    #[test]
+    fn estimated() {
+        let mut index = SizeIndex::default();
+        index.update_estimated(4, 1, 2);
+        index.set(1, 5);
+
+        index.update_estimated(6, 1, 2);
+        assert_eq!(index.total(), 15);
+
+        index.invalidate(1..2);
+        index.update_estimated(6, 1, 2);
+        assert_eq!(index.total(), 12);
+    }
+
+    #[test]
+    fn cross_axis_size_changed() {
+        let mut index = index(&[2, 3, 1]);
+
+        index.update(3, 2, |i| [1, 2, 1][i]);
+        assert_eq!(index.total(), 4);
+
+        index.set(1, 5);
+        index.update_estimated(3, 3, 2);
+        assert_eq!(index.total(), 6);
+    }
+}
@@ -1,9 +1,22 @@
+use std::{
+    fmt,
+    ops::{Bound, RangeBounds},
+};
+
+use ratatui::buffer::Buffer;
+
+use crate::{masonry::MasonryLayout, size_index::SizeIndex};
+
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Clone)]
 pub struct ListState {
     /// The selected item. If `None`, no item is currently selected.
     pub selected: Option<usize>,
 
+    /// The hovered item, e.g. the item under the mouse cursor. Used to apply
+    /// [`ListStyles::hovered`](crate::ListStyles::hovered).
+    pub hovered: Option<usize>,
+
     /// The total number of elements in the list. This is necessary to correctly
     /// handle item selection.
     pub(crate) num_elements: usize,
@@ -17,6 +30,48 @@ pub struct ListState {
     /// The state for the viewport. Keeps track which item to show
     /// first and how much it is truncated.
     pub(crate) view_state: ViewState,
+
+    /// The direction in which the selection was moved last. Used to skip
+    /// items that can not be selected.
+    pub(crate) direction: Direction,
+
+    /// Whether a sticky item has been shown. Avoids searching the list for
+    /// sticky items if there are none.
+    pub(crate) has_sticky_items: bool,
+
+    /// The result of the last lookup of the sticky item. Avoids building all
+    /// items before the viewport on every render.
+    pub(crate) sticky_lookup: Option<StickyLookup>,
+
+    /// The selection that was last checked to be selectable. Avoids building
+    /// the selected item again on every render.
+    pub(crate) checked_selection: Option<usize>,
+
+    /// The offset of the items along the cross axis, if they are larger than
+    /// the list.
+    pub(crate) cross_axis_offset: u16,
+
+    /// The placement of the items in masonry mode.
+    pub(crate) masonry: MasonryLayout,
+
+    /// The cached sizes of the items. Built on demand.
+    pub(crate) size_index: SizeIndex,
+
+    /// The fraction of the list to scroll to on the next render.
+    pub(crate) scroll_target: Option<f64>,
+
+    /// The buffer into which truncated items are rendered. Kept between
+    /// renders to avoid an allocation for every truncated item.
+    pub(crate) scratch: ScratchBuffer,
+
+    /// The selected item and its distance from the start of the viewport after
+    /// the last render. Used to keep the selected item in place if the sizes
+    /// of the items before it change.
+    pub(crate) anchor: Option<(usize, u16)>,
+
+    /// Whether the state was restored, e.g. deserialized. The restored
+    /// selection and offset are validated against the items on the next render.
+    pub(crate) restored: bool,
 }
 
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
@@ -28,13 +83,57 @@ pub(crate) struct ViewState {
     pub(crate) first_truncated: u16,
 }
 
+/// The last sticky item at or before the item `checked`. There is no sticky
+/// item between them.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct StickyLookup {
+    /// The index of the sticky item, `None` if there is none.
+    pub(crate) index: Option<usize>,
+
+    /// The last item that was checked.
+    pub(crate) checked: usize,
+}
+
+/// The buffer into which truncated items are rendered. Only its area is shown
+/// in the debug output, its content is left out.
+#[derive(Clone, Default)]
+pub(crate) struct ScratchBuffer(pub(crate) Buffer);
+
+impl fmt::Debug for ScratchBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScratchBuffer")
+            .field("area", &self.0.area)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The direction in which the selection moves through the list.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub(crate) enum Direction {
+    #[default]
+    Forward,
+    Backward,
+}
+
 impl Default for ListState {
     fn default() -> Self {
         Self {
             selected: None,
+            hovered: None,
             num_elements: 0,
             infinite_scrolling: true,
             view_state: ViewState::default(),
+            direction: Direction::Forward,
+            has_sticky_items: false,
+            sticky_lookup: None,
+            checked_selection: None,
+            cross_axis_offset: 0,
+            masonry: MasonryLayout::default(),
+            size_index: SizeIndex::default(),
+            scroll_target: None,
+            scratch: ScratchBuffer::default(),
+            anchor: None,
+            restored: false,
         }
     }
 }
@@ -61,6 +160,7 @@ impl ListState {
 
     /// Selects the next element of the list. If circular is true,
     /// calling next on the last element selects the first.
+    /// Items that can not be selected are skipped on the next render.
     ///
     /// # Example
     ///
@@ -89,10 +189,12 @@ impl ListState {
             None => 0,
         };
         self.select(Some(i));
+        self.direction = Direction::Forward;
     }
 
     /// Selects the previous element of the list. If circular is true,
     /// calling previous on the first element selects the last.
+    /// Items that can not be selected are skipped on the next render.
     ///
     /// # Example
     ///
@@ -121,10 +223,193 @@ impl ListState {
             None => 0,
         };
         self.select(Some(i));
+        self.direction = Direction::Backward;
+    }
+
+    /// Selects the next item in the same column in masonry mode, see
+    /// [`ListView::masonry`](crate::ListView::masonry). Keeps the selection if
+    /// there is no item below. In other layouts, this is the same as
+    /// [`ListState::next`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_widget_list::ListState;
+    ///
+    /// let mut list_state = ListState::default();
+    /// list_state.next_in_column();
+    /// ```
+    pub fn next_in_column(&mut self) {
+        match self.selected {
+            Some(selected) if selected < self.masonry.placements.len() => {
+                if let Some(index) = self.masonry.next_in_column(selected) {
+                    self.select(Some(index));
+                    self.direction = Direction::Forward;
+                }
+            }
+            _ => self.next(),
+        }
+    }
+
+    /// Selects the previous item in the same column in masonry mode, see
+    /// [`ListView::masonry`](crate::ListView::masonry). Keeps the selection if
+    /// there is no item above. In other layouts, this is the same as
+    /// [`ListState::previous`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_widget_list::ListState;
+    ///
+    /// let mut list_state = ListState::default();
+    /// list_state.previous_in_column();
+    /// ```
+    pub fn previous_in_column(&mut self) {
+        match self.selected {
+            Some(selected) if selected < self.masonry.placements.len() => {
+                if let Some(index) = self.masonry.previous_in_column(selected) {
+                    self.select(Some(index));
+                    self.direction = Direction::Backward;
+                }
+            }
+            _ => self.previous(),
+        }
+    }
+
+    /// Scrolls the items one column to the left, or one row up in horizontal
+    /// lists. Only has an effect if the items are larger than the list along
+    /// the cross axis, see [`ListView::virtual_cross_axis_size`](crate::ListView::virtual_cross_axis_size).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_widget_list::ListState;
+    ///
+    /// let mut list_state = ListState::default();
+    /// list_state.scroll_left();
+    /// ```
+    pub fn scroll_left(&mut self) {
+        self.cross_axis_offset = self.cross_axis_offset.saturating_sub(1);
+    }
+
+    /// Scrolls the items one column to the right, or one row down in horizontal
+    /// lists. Only has an effect if the items are larger than the list along
+    /// the cross axis, see [`ListView::virtual_cross_axis_size`](crate::ListView::virtual_cross_axis_size).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_widget_list::ListState;
+    ///
+    /// let mut list_state = ListState::default();
+    /// list_state.scroll_right();
+    /// ```
+    pub fn scroll_right(&mut self) {
+        self.cross_axis_offset = self.cross_axis_offset.saturating_add(1);
+    }
+
+    /// Selects the item at the given fraction of the total size of the list and
+    /// scrolls it to the top of the viewport on the next render. A fraction
+    /// of `0.0` selects the first, `1.0` the last item.
+    ///
+    /// The sizes of the items are cached, so that repeated jumps are cheap. If
+    /// the size of items changes, call [`ListState::invalidate`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_widget_list::ListState;
+    ///
+    /// let mut list_state = ListState::default();
+    /// list_state.scroll_to_fraction(0.5);
+    /// ```
+    pub fn scroll_to_fraction(&mut self, fraction: f64) {
+        self.scroll_target = Some(fraction.clamp(0.0, 1.0));
+    }
+
+    /// Marks the cached sizes of the items in `range` as outdated. They are
+    /// measured again the next time the cached sizes are needed. The cache is
+    /// rebuilt automatically if the number of items changes. The cached sticky
+    /// item is looked up again as well, and whether the selected item can be
+    /// selected is checked again.
+    ///
+    /// The cached sizes are measured with the items not being selected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tui_widget_list::ListState;
+    ///
+    /// let mut list_state = ListState::default();
+    /// list_state.invalidate(2..5);
+    /// ```
+    pub fn invalidate<R: RangeBounds<usize>>(&mut self, range: R) {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => usize::MAX,
+        };
+        self.size_index.invalidate(start..end);
+        self.sticky_lookup = None;
+        self.checked_selection = None;
+        if start < self.masonry.placements.len() {
+            self.masonry.truncate(start);
+        }
     }
 
     /// Updates the number of elements that are present in the list.
     pub(crate) fn set_num_elements(&mut self, num_elements: usize) {
+        if self.num_elements != num_elements {
+            self.sticky_lookup = None;
+            self.checked_selection = None;
+        }
         self.num_elements = num_elements;
     }
 }
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{ListState, ViewState};
+
+    /// The part of the `ListState` that is persisted: the selection and the
+    /// position of the viewport.
+    #[derive(Serialize, Deserialize)]
+    struct PersistedListState {
+        selected: Option<usize>,
+        offset: usize,
+        first_truncated: u16,
+    }
+
+    impl Serialize for ListState {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            PersistedListState {
+                selected: self.selected,
+                offset: self.view_state.offset,
+                first_truncated: self.view_state.first_truncated,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ListState {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let state = PersistedListState::deserialize(deserializer)?;
+            Ok(ListState {
+                selected: state.selected,
+                view_state: ViewState {
+                    offset: state.offset,
+                    first_truncated: state.first_truncated,
+                },
+                restored: true,
+                ..ListState::default()
+            })
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use ratatui::{buffer::Buffer, layout::Rect, style::Style};
+
+/// Styles that are patched onto the items of a [`ListView`](crate::ListView)
+/// after they are rendered, so that builders do not need to style the items
+/// depending on their index or selection.
+///
+/// The styles are applied in the order `even`/`odd`, `disabled`, `hovered`
+/// and `selected`, later styles take precedence.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::style::{Color, Style};
+/// use tui_widget_list::{ListBuilder, ListStyles, ListView};
+///
+/// let styles = ListStyles::default()
+///     .odd(Style::new().bg(Color::DarkGray))
+///     .selected(Style::new().bg(Color::Yellow));
+/// let builder = ListBuilder::new(|context| (format!("Item {0}", context.index), 1));
+/// let list = ListView::new(builder, 20).styles(styles);
+/// ```
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListStyles {
+    /// The style of items with an even index.
+    pub even: Style,
+
+    /// The style of items with an odd index.
+    pub odd: Style,
+
+    /// The style of the selected item.
+    pub selected: Style,
+
+    /// The style of the hovered item, see [`ListState::hovered`](crate::ListState::hovered).
+    pub hovered: Style,
+
+    /// The style of items that can not be selected.
+    pub disabled: Style,
+}
+
+impl ListStyles {
+    /// Sets the style of items with an even index.
+    #[must_use]
+    pub fn even<S: Into<Style>>(mut self, style: S) -> Self {
+        self.even = style.into();
+        self
+    }
+
+    /// Sets the style of items with an odd index.
+    #[must_use]
+    pub fn odd<S: Into<Style>>(mut self, style: S) -> Self {
+        self.odd = style.into();
+        self
+    }
+
+    /// Sets the style of the selected item.
+    #[must_use]
+    pub fn selected<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selected = style.into();
+        self
+    }
+
+    /// Sets the style of the hovered item.
+    #[must_use]
+    pub fn hovered<S: Into<Style>>(mut self, style: S) -> Self {
+        self.hovered = style.into();
+        self
+    }
+
+    /// Sets the style of items that can not be selected.
+    #[must_use]
+    pub fn disabled<S: Into<Style>>(mut self, style: S) -> Self {
+        self.disabled = style.into();
+        self
+    }
+
+    /// Patches the styles onto the area of a rendered item.
+    pub(crate) fn patch(&self, buf: &mut Buffer, area: Rect, item: ItemStatus) {
+        if *self == Self::default() {
+            return;
+        }
+        let base = if item.index % 2 == 0 {
+            self.even
+        } else {
+            self.odd
+        };
+        buf.set_style(area, base);
+        if !item.is_selectable {
+            buf.set_style(area, self.disabled);
+        }
+        if item.is_hovered {
+            buf.set_style(area, self.hovered);
+        }
+        if item.is_selected {
+            buf.set_style(area, self.selected);
+        }
+    }
+}
+
+/// The status of a rendered item that determines its style.
+pub(crate) struct ItemStatus {
+    /// The index of the item.
+    pub(crate) index: usize,
+
+    /// Whether the item is selected.
+    pub(crate) is_selected: bool,
+
+    /// Whether the item is hovered.
+    pub(crate) is_hovered: bool,
+
+    /// Whether the item can be selected.
+    pub(crate) is_selectable: bool,
+}
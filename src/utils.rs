@@ -1,9 +1,16 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::io::Write;
+use std::ops::Range;
 use std::{cmp::Ordering, fs::OpenOptions};
 
-use crate::{view::Truncation, ListBuildContext, ListBuilder, ListState, ScrollAxis};
+use ratatui::widgets::Block;
+
+use crate::{
+    state::StickyLookup,
+    view::{BuiltItem, LayoutHints, Truncation},
+    ListBuildContext, ListBuilder, ListState, ScrollAxis,
+};
 
 /// Determines the new viewport layout based on the previous viewport state, i.e.
 /// the offset of the first element and the truncation of the first element.
@@ -26,7 +33,7 @@ use crate::{view::Truncation, ListBuildContext, ListBuilder, ListState, ScrollAx
 pub(crate) fn layout_on_viewport<T>(
     state: &mut ListState,
     builder: &ListBuilder<T>,
-    item_count: usize,
+    items: Range<usize>,
     total_main_axis_size: u16,
     cross_axis_size: u16,
     scroll_axis: ScrollAxis,
@@ -39,13 +46,13 @@ pub(crate) fn layout_on_viewport<T>(
     let mut viewport: HashMap<usize, ViewportElement<T>> = HashMap::new();
 
     // If none is selected, the first item should be show on top of the viewport.
-    let selected = state.selected.unwrap_or(0);
+    let selected = selected_in_range(state, &items);
 
     // Calculate the effective scroll padding for each widget
     let effective_scroll_padding_by_index = calculate_effective_scroll_padding(
         state,
         builder,
-        item_count,
+        items.clone(),
         cross_axis_size,
         scroll_axis,
         scroll_padding,
@@ -54,6 +61,7 @@ pub(crate) fn layout_on_viewport<T>(
     update_offset(
         state,
         &mut cacher,
+        items.start,
         selected,
         &effective_scroll_padding_by_index,
     );
@@ -64,7 +72,7 @@ pub(crate) fn layout_on_viewport<T>(
         state,
         &mut cacher,
         state.view_state.offset,
-        item_count,
+        items.end,
         total_main_axis_size,
         selected,
         &effective_scroll_padding_by_index,
@@ -75,7 +83,7 @@ pub(crate) fn layout_on_viewport<T>(
     }
 
     for (key, value) in viewport.drain() {
-        cacher.insert(key, value.widget, value.main_axis_size);
+        cacher.insert(key, value.into_item());
     }
 
     // Perform a backward pass, starting from the `selected` item.
@@ -85,7 +93,7 @@ pub(crate) fn layout_on_viewport<T>(
         &mut viewport,
         state,
         &mut cacher,
-        item_count,
+        items,
         total_main_axis_size,
         selected,
         &effective_scroll_padding_by_index,
@@ -94,17 +102,443 @@ pub(crate) fn layout_on_viewport<T>(
     viewport
 }
 
+/// Selects the item at `fraction` of the total size of the list and makes it
+/// the first item on the viewport. The offset is moved up if the items after
+/// the selected item do not fill the viewport.
+///
+/// The positions are looked up in the cached size index of the state. With a
+/// fixed item size, they are computed arithmetically instead.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn scroll_to_fraction<T>(
+    state: &mut ListState,
+    builder: &ListBuilder<T>,
+    item_count: usize,
+    estimated_item_size: Option<u16>,
+    fraction: f64,
+    total_main_axis_size: u16,
+    cross_axis_size: u16,
+    scroll_axis: ScrollAxis,
+) {
+    if item_count == 0 {
+        return;
+    }
+
+    let (selected, (offset, first_truncated)) = if let Some(size) = builder.fixed_main_axis_size() {
+        let size = u64::from(size);
+        let total = size * item_count as u64;
+        let find = |position: u64| {
+            let index = usize::try_from(position / size).unwrap_or(usize::MAX);
+            match index.cmp(&item_count) {
+                Ordering::Less => (index, (position % size) as u16),
+                _ => (item_count - 1, 0),
+            }
+        };
+        (
+            find(fraction_of(total, fraction)).0,
+            find(total.saturating_sub(u64::from(total_main_axis_size))),
+        )
+    } else {
+        update_size_index(
+            state,
+            builder,
+            item_count,
+            estimated_item_size,
+            cross_axis_size,
+            scroll_axis,
+        );
+        let index = &state.size_index;
+        let total = index.total();
+        let (selected, _) = index.find(fraction_of(total, fraction)).unwrap_or_default();
+        let last = index
+            .find(total.saturating_sub(u64::from(total_main_axis_size)))
+            .unwrap_or_default();
+        (selected, last)
+    };
+
+    state.select(Some(selected));
+    if selected <= offset {
+        state.view_state.offset = selected;
+        state.view_state.first_truncated = 0;
+    } else {
+        state.view_state.offset = offset;
+        state.view_state.first_truncated = first_truncated;
+    }
+}
+
+/// Returns the position of the viewport and the total size of the list along
+/// the main axis in cells.
+pub(crate) fn scroll_position<T>(
+    state: &mut ListState,
+    builder: &ListBuilder<T>,
+    item_count: usize,
+    estimated_item_size: Option<u16>,
+    cross_axis_size: u16,
+    scroll_axis: ScrollAxis,
+) -> (u64, u64) {
+    let first_truncated = u64::from(state.view_state.first_truncated);
+    if let Some(size) = builder.fixed_main_axis_size() {
+        let size = u64::from(size);
+        let position = size * state.view_state.offset as u64 + first_truncated;
+        return (position, size * item_count as u64);
+    }
+
+    update_size_index(
+        state,
+        builder,
+        item_count,
+        estimated_item_size,
+        cross_axis_size,
+        scroll_axis,
+    );
+    let index = &state.size_index;
+    let position = index.prefix_sum(state.view_state.offset) + first_truncated;
+    (position, index.total())
+}
+
+/// Moves the offset such that the selected item has the same distance from the
+/// start of the viewport as in the last render. Nothing is done if the
+/// selection changed since then.
+pub(crate) fn restore_anchor<T>(
+    state: &mut ListState,
+    builder: &ListBuilder<T>,
+    items: Range<usize>,
+    cross_axis_size: u16,
+    scroll_axis: ScrollAxis,
+) {
+    let Some((selected, distance)) = state.anchor else {
+        return;
+    };
+    if state.selected != Some(selected) || !items.contains(&selected) {
+        return;
+    }
+
+    let mut offset = selected;
+    let mut first_truncated = 0;
+    let mut remaining = distance;
+    while remaining > 0 && offset > items.start {
+        offset -= 1;
+        let size = builder.main_axis_size(&ListBuildContext {
+            index: offset,
+            is_selected: false,
+            scroll_axis,
+            cross_axis_size,
+        });
+        first_truncated = size.saturating_sub(remaining);
+        remaining = remaining.saturating_sub(size);
+    }
+    state.view_state.offset = offset;
+    state.view_state.first_truncated = first_truncated;
+}
+
+/// Converts a size in cells to `usize`, saturating on overflow.
+pub(crate) fn to_usize(value: u64) -> usize {
+    usize::try_from(value).unwrap_or(usize::MAX)
+}
+
+/// Brings the cached size index of the state up to date. With an estimated item
+/// size, items are not measured but assumed to have the estimated size until
+/// they are laid out.
+pub(crate) fn update_size_index<T>(
+    state: &mut ListState,
+    builder: &ListBuilder<T>,
+    item_count: usize,
+    estimated_item_size: Option<u16>,
+    cross_axis_size: u16,
+    scroll_axis: ScrollAxis,
+) {
+    if let Some(estimated_item_size) = estimated_item_size {
+        state
+            .size_index
+            .update_estimated(item_count, cross_axis_size, estimated_item_size);
+        return;
+    }
+
+    state
+        .size_index
+        .update(item_count, cross_axis_size, |index| {
+            builder.main_axis_size(&ListBuildContext {
+                index,
+                is_selected: false,
+                scroll_axis,
+                cross_axis_size,
+            })
+        });
+}
+
+/// Returns the position at `fraction` of `total`. The position at `1.0` is the
+/// last position inside `total`.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+fn fraction_of(total: u64, fraction: f64) -> u64 {
+    ((total as f64 * fraction) as u64).min(total.saturating_sub(1))
+}
+
+/// Lays out pinned items, which are always shown regardless of the scroll
+/// position. The pinned items take up space from `available_size`. Items that
+/// do not fit are truncated at the bottom or not shown at all.
+///
+/// Returns the pinned items that should be rendered together with their visible
+/// size along the main axis.
+pub(crate) fn layout_pinned<T>(
+    state: &ListState,
+    builder: &ListBuilder<T>,
+    items: Range<usize>,
+    available_size: &mut u16,
+    cross_axis_size: u16,
+    scroll_axis: ScrollAxis,
+) -> Vec<(ViewportElement<T>, u16)> {
+    let mut cacher = WidgetCacher::new(builder, scroll_axis, cross_axis_size, state.selected);
+    let mut pinned = Vec::new();
+
+    for index in items {
+        if *available_size == 0 {
+            break;
+        }
+        let item = cacher.get(index);
+        let main_axis_size = item.main_axis_size;
+
+        let truncation = match main_axis_size.cmp(available_size) {
+            Ordering::Greater => Truncation::Bot(main_axis_size - *available_size),
+            _ => Truncation::None,
+        };
+        let visible_main_axis_size = main_axis_size - truncation.value();
+        pinned.push((
+            ViewportElement::from_item(item, truncation),
+            visible_main_axis_size,
+        ));
+
+        *available_size -= visible_main_axis_size;
+    }
+
+    pinned
+}
+
+/// Returns the last sticky item at or before `index`, together with its index.
+///
+/// The result is cached in the state. Only the items after the last checked
+/// item are built, unless the list was scrolled above the cached sticky item.
+pub(crate) fn find_sticky<T>(
+    state: &mut ListState,
+    builder: &ListBuilder<T>,
+    items: Range<usize>,
+    index: usize,
+    cross_axis_size: u16,
+    scroll_axis: ScrollAxis,
+) -> Option<(usize, BuiltItem<T>)> {
+    let build = |index| {
+        builder.call_closure(&ListBuildContext {
+            index,
+            is_selected: state.selected == Some(index),
+            scroll_axis,
+            cross_axis_size,
+        })
+    };
+
+    // The cached lookup is valid if its sticky item is not after `index`.
+    let cached = state
+        .sticky_lookup
+        .filter(|lookup| lookup.index.map_or(true, |sticky| sticky <= index));
+    let scan_start = cached.map_or(items.start, |lookup| lookup.checked + 1);
+
+    let found = (scan_start.max(items.start)..=index)
+        .rev()
+        .map(|index| (index, build(index)))
+        .find(|(_, item)| item.hints.sticky)
+        .or_else(|| {
+            let sticky = cached?.index.filter(|sticky| *sticky >= items.start)?;
+            Some((sticky, build(sticky))).filter(|(_, item)| item.hints.sticky)
+        });
+
+    state.sticky_lookup = Some(StickyLookup {
+        index: found.as_ref().map(|(index, _)| *index),
+        checked: cached.map_or(index, |lookup| lookup.checked.max(index)),
+    });
+    found
+}
+
+/// Determines the new viewport layout if only whole items should be shown.
+///
+/// The offset always points to an item that is fully visible and advances by
+/// entire items. Space that is left after the last item that fits remains empty.
+/// An item larger than the viewport is shown on its own and clipped at the bottom.
+pub(crate) fn layout_on_viewport_snapped<T>(
+    state: &mut ListState,
+    builder: &ListBuilder<T>,
+    items: Range<usize>,
+    total_main_axis_size: u16,
+    cross_axis_size: u16,
+    scroll_axis: ScrollAxis,
+    scroll_padding: u16,
+) -> HashMap<usize, ViewportElement<T>> {
+    let mut cacher = WidgetCacher::new(builder, scroll_axis, cross_axis_size, state.selected);
+    let mut viewport: HashMap<usize, ViewportElement<T>> = HashMap::new();
+
+    // If none is selected, the first item should be show on top of the viewport.
+    let selected = selected_in_range(state, &items);
+
+    let selected_size = cacher.get_height(selected);
+
+    // Whole items are added on both sides of the selected item until the scroll
+    // padding is covered. Each side may use at most half of the remaining space,
+    // so that the padding above and below fit onto the viewport at the same time.
+    let padding_budget = total_main_axis_size.saturating_sub(selected_size) / 2;
+
+    // If the selected item does not fit, there is no budget for padding, not
+    // even for items of size zero.
+    let mut first = selected;
+    let mut padding_size = 0;
+    while padding_budget > 0 && first > items.start && padding_size < scroll_padding {
+        let size = cacher.get_height(first - 1);
+        if padding_size + size > padding_budget {
+            break;
+        }
+        padding_size += size;
+        first -= 1;
+    }
+
+    let mut last = selected;
+    let mut padding_size = 0;
+    while padding_budget > 0 && last + 1 < items.end && padding_size < scroll_padding {
+        let size = cacher.get_height(last + 1);
+        if padding_size + size > padding_budget {
+            break;
+        }
+        padding_size += size;
+        last += 1;
+    }
+
+    // The smallest offset for which all items up to `last` fit onto the viewport.
+    let mut lowest_offset = last;
+    let mut size = cacher.get_height(last);
+    while lowest_offset > items.start {
+        let next_size = cacher.get_height(lowest_offset - 1);
+        if size + next_size > total_main_axis_size {
+            break;
+        }
+        size += next_size;
+        lowest_offset -= 1;
+    }
+
+    // Keep the previous offset if possible, otherwise scroll up or down.
+    state.view_state.offset = state
+        .view_state
+        .offset
+        .clamp(lowest_offset.min(first), first);
+    state.view_state.first_truncated = 0;
+
+    // Fill the viewport with whole items, starting from the offset.
+    let mut available_size = total_main_axis_size;
+    for index in state.view_state.offset..items.end {
+        let is_first = index == state.view_state.offset;
+        let item = cacher.get(index);
+        let main_axis_size = item.main_axis_size;
+
+        if main_axis_size > available_size {
+            // An item larger than the viewport is clipped at the bottom.
+            if is_first {
+                let truncation = Truncation::Bot(main_axis_size - available_size);
+                viewport.insert(index, ViewportElement::from_item(item, truncation));
+            }
+            break;
+        }
+
+        viewport.insert(index, ViewportElement::from_item(item, Truncation::None));
+        available_size -= main_axis_size;
+    }
+
+    viewport
+}
+
+/// Determines the new viewport layout such that the selected item is centered.
+///
+/// The items before and after the selected item fill the remaining space. The
+/// offset is not clamped at the start or the end of the list, i.e. blank space
+/// remains before the first and after the last item.
+pub(crate) fn layout_on_viewport_centered<T>(
+    state: &mut ListState,
+    builder: &ListBuilder<T>,
+    items: Range<usize>,
+    total_main_axis_size: u16,
+    cross_axis_size: u16,
+    scroll_axis: ScrollAxis,
+) -> HashMap<usize, ViewportElement<T>> {
+    let mut cacher = WidgetCacher::new(builder, scroll_axis, cross_axis_size, state.selected);
+    let mut viewport: HashMap<usize, ViewportElement<T>> = HashMap::new();
+
+    // If none is selected, the first item should be centered.
+    let selected = selected_in_range(state, &items);
+
+    // An item larger than the viewport is clipped at the bottom.
+    let item = cacher.get(selected);
+    let selected_size = item.main_axis_size;
+    let truncation = match selected_size.cmp(&total_main_axis_size) {
+        Ordering::Greater => Truncation::Bot(selected_size - total_main_axis_size),
+        _ => Truncation::None,
+    };
+    viewport.insert(selected, ViewportElement::from_item(item, truncation));
+    state.view_state.offset = selected;
+    state.view_state.first_truncated = 0;
+
+    let free_space = total_main_axis_size.saturating_sub(selected_size);
+
+    // Fill the space before the selected item.
+    let mut available_size = free_space / 2;
+    for index in (items.start..selected).rev() {
+        if available_size == 0 {
+            break;
+        }
+        let item = cacher.get(index);
+        let main_axis_size = item.main_axis_size;
+
+        state.view_state.offset = index;
+        let truncation = match main_axis_size.cmp(&available_size) {
+            Ordering::Greater => {
+                state.view_state.first_truncated = main_axis_size - available_size;
+                Truncation::Top(state.view_state.first_truncated)
+            }
+            _ => Truncation::None,
+        };
+        viewport.insert(index, ViewportElement::from_item(item, truncation));
+
+        available_size = available_size.saturating_sub(main_axis_size);
+    }
+
+    // Fill the space after the selected item.
+    let mut available_size = free_space - free_space / 2;
+    for index in selected + 1..items.end {
+        if available_size == 0 {
+            break;
+        }
+        let item = cacher.get(index);
+        let main_axis_size = item.main_axis_size;
+
+        let truncation = match main_axis_size.cmp(&available_size) {
+            Ordering::Greater => Truncation::Bot(main_axis_size - available_size),
+            _ => Truncation::None,
+        };
+        viewport.insert(index, ViewportElement::from_item(item, truncation));
+
+        available_size = available_size.saturating_sub(main_axis_size);
+    }
+
+    viewport
+}
+
 // If the selected value is smaller than the offset, we roll
 // the offset so that the selected value is at the top. The complicated
 // part is that we also need to account for scroll padding.
 fn update_offset<T>(
     state: &mut ListState,
     cacher: &mut WidgetCacher<T>,
+    first_index: usize,
     selected: usize,
-    scroll_padding_by_index: &HashMap<usize, u16>,
+    scroll_padding_by_index: &EffectiveScrollPadding,
 ) {
     // Get the top padding for scrolling or default to 0 if not present
-    let scroll_padding_top = *scroll_padding_by_index.get(&selected).unwrap_or(&0);
+    let scroll_padding_top = scroll_padding_by_index.get(selected);
 
     // Initialize variables
     let mut first_element = selected;
@@ -112,7 +546,7 @@ fn update_offset<T>(
     let mut available_size = scroll_padding_top;
 
     // Traverse from the selected index up to the beginning
-    for index in (0..=selected).rev() {
+    for index in (first_index..=selected).rev() {
         // Update the first element in view
         first_element = index;
 
@@ -142,6 +576,23 @@ fn update_offset<T>(
     }
 }
 
+/// Returns the index of the item that should be brought into view. If none is
+/// selected, this is the first item. A selected item outside of `items`, i.e. a
+/// pinned item, is mapped to the closest item inside. The offset of the viewport
+/// is kept inside `items` as well.
+fn selected_in_range(state: &mut ListState, items: &Range<usize>) -> usize {
+    let last = items.end.saturating_sub(1).max(items.start);
+    if !(items.start..=last).contains(&state.view_state.offset) {
+        state.view_state.offset = state.view_state.offset.clamp(items.start, last);
+        state.view_state.first_truncated = 0;
+    }
+
+    state
+        .selected
+        .unwrap_or(items.start)
+        .clamp(items.start, last)
+}
+
 /// Iterate forward through the list of widgets.
 ///
 /// Returns true if the selected widget is inside the viewport.
@@ -154,7 +605,7 @@ fn forward_pass<T>(
     item_count: usize,
     total_main_axis_size: u16,
     selected: usize,
-    scroll_padding_by_index: &HashMap<usize, u16>,
+    scroll_padding_by_index: &EffectiveScrollPadding,
 ) -> bool {
     // Check if the selected item is in the current view
     let mut found_last = false;
@@ -163,7 +614,8 @@ fn forward_pass<T>(
     for index in offset..item_count {
         let is_first = index == state.view_state.offset;
 
-        let (widget, total_main_axis_size) = cacher.get(index);
+        let item = cacher.get(index);
+        let total_main_axis_size = item.main_axis_size;
 
         let main_axis_size = if is_first {
             total_main_axis_size.saturating_sub(state.view_state.first_truncated)
@@ -172,8 +624,8 @@ fn forward_pass<T>(
         };
 
         // The effective available size considering scroll padding.
-        let scroll_padding_effective = scroll_padding_by_index.get(&index).unwrap_or(&0);
-        let available_effective = available_size.saturating_sub(*scroll_padding_effective);
+        let scroll_padding_effective = scroll_padding_by_index.get(index);
+        let available_effective = available_size.saturating_sub(scroll_padding_effective);
 
         // Out of bounds
         if !found_selected && main_axis_size >= available_effective {
@@ -216,10 +668,7 @@ fn forward_pass<T>(
             }
         };
 
-        viewport.insert(
-            index,
-            ViewportElement::new(widget, total_main_axis_size, truncation.clone()),
-        );
+        viewport.insert(index, ViewportElement::from_item(item, truncation.clone()));
 
         if found_last {
             break;
@@ -238,16 +687,17 @@ fn backward_pass<T>(
     viewport: &mut HashMap<usize, ViewportElement<T>>,
     state: &mut ListState,
     cacher: &mut WidgetCacher<T>,
-    item_count: usize,
+    items: Range<usize>,
     total_main_axis_size: u16,
     selected: usize,
-    scroll_padding_by_index: &HashMap<usize, u16>,
+    scroll_padding_by_index: &EffectiveScrollPadding,
 ) {
     let mut found_first = false;
     let mut available_size = total_main_axis_size;
-    let scroll_padding_effective = *scroll_padding_by_index.get(&selected).unwrap_or(&0);
-    for index in (0..=selected).rev() {
-        let (widget, main_axis_size) = cacher.get(index);
+    let scroll_padding_effective = scroll_padding_by_index.get(selected);
+    for index in (items.start..=selected).rev() {
+        let item = cacher.get(index);
+        let main_axis_size = item.main_axis_size;
 
         let available_effective = available_size.saturating_sub(scroll_padding_effective);
 
@@ -275,7 +725,7 @@ fn backward_pass<T>(
             Ordering::Greater => Truncation::None,
         };
 
-        let element = ViewportElement::new(widget, main_axis_size, truncation);
+        let element = ViewportElement::from_item(item, truncation);
         viewport.insert(index, element);
 
         if found_first {
@@ -289,17 +739,15 @@ fn backward_pass<T>(
     // Only necessary for lists with scroll padding.
     if scroll_padding_effective > 0 {
         available_size = scroll_padding_effective;
-        for index in selected + 1..item_count {
-            let (widget, main_axis_size) = cacher.get(index);
+        for index in selected + 1..items.end {
+            let item = cacher.get(index);
+            let main_axis_size = item.main_axis_size;
 
             let truncation = match available_size.cmp(&main_axis_size) {
                 Ordering::Greater | Ordering::Equal => Truncation::None,
                 Ordering::Less => Truncation::Bot(main_axis_size.saturating_sub(available_size)),
             };
-            viewport.insert(
-                index,
-                ViewportElement::new(widget, main_axis_size, truncation),
-            );
+            viewport.insert(index, ViewportElement::from_item(item, truncation));
 
             available_size = available_size.saturating_sub(main_axis_size);
             // Out of bounds
@@ -316,62 +764,110 @@ fn backward_pass<T>(
 /// not receive padding.
 ///
 /// Returns:
-/// A `HashMap` where the keys are the indices of the list items and the values are
-/// the corresponding padding applied. If the item is not on the list, `scroll_padding`
-/// is unaltered.
+/// The effective padding of each item. Only the items at the beginning and the
+/// end of the list are evaluated, all other items receive the full `scroll_padding`.
+/// If all items have the same size, the padding is computed arithmetically.
 fn calculate_effective_scroll_padding<T>(
     state: &mut ListState,
     builder: &ListBuilder<T>,
-    item_count: usize,
+    items: Range<usize>,
     cross_axis_size: u16,
     scroll_axis: ScrollAxis,
     scroll_padding: u16,
-) -> HashMap<usize, u16> {
-    let mut padding_by_element = HashMap::new();
-    let mut total_main_axis_size = 0;
+) -> EffectiveScrollPadding {
+    let mut padding = EffectiveScrollPadding {
+        scroll_padding,
+        padding_by_index: HashMap::new(),
+        fixed_item_size: builder.fixed_main_axis_size(),
+        items: items.clone(),
+    };
+    if padding.fixed_item_size.is_some() {
+        return padding;
+    }
 
-    for index in 0..item_count {
+    let mut total_main_axis_size = 0;
+    for index in items.clone() {
+        // Stop applying padding once the scroll padding limit is reached
         if total_main_axis_size >= scroll_padding {
-            padding_by_element.insert(index, scroll_padding);
-            continue;
+            break;
         }
-        padding_by_element.insert(index, total_main_axis_size);
+        padding.padding_by_index.insert(index, total_main_axis_size);
 
         let context = ListBuildContext {
             index,
-            is_selected: state.selected.map_or(false, |j| index == j),
+            is_selected: state.selected == Some(index),
             scroll_axis,
             cross_axis_size,
         };
 
-        let (_, item_main_axis_size) = builder.call_closure(&context);
+        let item_main_axis_size = builder.main_axis_size(&context);
         total_main_axis_size += item_main_axis_size;
     }
 
     total_main_axis_size = 0;
-    for index in (0..item_count).rev() {
+    for index in items.rev() {
         // Stop applying padding once the scroll padding limit is reached
         if total_main_axis_size >= scroll_padding {
             break;
         }
-        padding_by_element.insert(index, total_main_axis_size);
+        // Items close to both edges keep the smaller padding.
+        let padding_before = padding.get(index);
+        padding
+            .padding_by_index
+            .insert(index, padding_before.min(total_main_axis_size));
 
         let context = ListBuildContext {
             index,
-            is_selected: state.selected.map_or(false, |j| index == j),
+            is_selected: state.selected == Some(index),
             scroll_axis,
             cross_axis_size,
         };
 
-        let (_, item_main_axis_size) = builder.call_closure(&context);
+        let item_main_axis_size = builder.main_axis_size(&context);
         total_main_axis_size += item_main_axis_size;
     }
 
-    padding_by_element
+    padding
+}
+
+/// The effective scroll padding of the items in a list.
+pub(crate) struct EffectiveScrollPadding {
+    /// The scroll padding of items that are not close to the edges of the list.
+    scroll_padding: u16,
+
+    /// The reduced padding of items close to the edges of the list.
+    padding_by_index: HashMap<usize, u16>,
+
+    /// The size of the items if all items have the same size.
+    fixed_item_size: Option<u16>,
+
+    /// The items of the list.
+    items: Range<usize>,
+}
+
+impl EffectiveScrollPadding {
+    /// Returns the effective scroll padding of an item.
+    pub(crate) fn get(&self, index: usize) -> u16 {
+        if let Some(size) = self.fixed_item_size {
+            let items_before = index.saturating_sub(self.items.start);
+            let items_after = self.items.end.saturating_sub(index + 1);
+            let size_before = items_before.saturating_mul(usize::from(size));
+            let size_after = items_after.saturating_mul(usize::from(size));
+            let padding = size_before.min(size_after);
+            return u16::try_from(padding).map_or(self.scroll_padding, |padding| {
+                padding.min(self.scroll_padding)
+            });
+        }
+
+        self.padding_by_index
+            .get(&index)
+            .copied()
+            .unwrap_or(self.scroll_padding)
+    }
 }
 
 struct WidgetCacher<'a, T> {
-    cache: HashMap<usize, (T, u16)>,
+    cache: HashMap<usize, BuiltItem<T>>,
     builder: &'a ListBuilder<T>,
     scroll_axis: ScrollAxis,
     cross_axis_size: u16,
@@ -396,11 +892,11 @@ impl<'a, T> WidgetCacher<'a, T> {
     }
 
     // Gets the widget and the height. Removes the widget from the cache.
-    fn get(&mut self, index: usize) -> (T, u16) {
-        let is_selected = self.selected.map_or(false, |j| index == j);
+    fn get(&mut self, index: usize) -> BuiltItem<T> {
+        let is_selected = self.selected == Some(index);
         // Check if the widget is already in cache
-        if let Some((widget, main_axis_size)) = self.cache.remove(&index) {
-            return (widget, main_axis_size);
+        if let Some(item) = self.cache.remove(&index) {
+            return item;
         }
 
         // Create the context for the builder
@@ -412,17 +908,15 @@ impl<'a, T> WidgetCacher<'a, T> {
         };
 
         // Call the builder to get the widget
-        let (widget, main_axis_size) = self.builder.call_closure(&context);
-
-        (widget, main_axis_size)
+        self.builder.call_closure(&context)
     }
 
     // Gets the height.
     fn get_height(&mut self, index: usize) -> u16 {
-        let is_selected = self.selected.map_or(false, |j| index == j);
+        let is_selected = self.selected == Some(index);
         // Check if the widget is already in cache
-        if let Some(&(_, main_axis_size)) = self.cache.get(&index) {
-            return main_axis_size;
+        if let Some(item) = self.cache.get(&index) {
+            return item.main_axis_size;
         }
 
         // Create the context for the builder
@@ -433,17 +927,23 @@ impl<'a, T> WidgetCacher<'a, T> {
             cross_axis_size: self.cross_axis_size,
         };
 
+        // Prefer the measure closure, which does not construct the widget
+        if let Some(main_axis_size) = self.builder.call_measure(&context) {
+            return main_axis_size;
+        }
+
         // Call the builder to get the widget
-        let (widget, main_axis_size) = self.builder.call_closure(&context);
+        let item = self.builder.call_closure(&context);
+        let main_axis_size = item.main_axis_size;
 
         // Store the widget in the cache
-        self.cache.insert(index, (widget, main_axis_size));
+        self.cache.insert(index, item);
 
         main_axis_size
     }
 
-    fn insert(&mut self, index: usize, widget: T, main_axis_size: u16) {
-        self.cache.insert(index, (widget, main_axis_size));
+    fn insert(&mut self, index: usize, item: BuiltItem<T>) {
+        self.cache.insert(index, item);
     }
 }
 
@@ -460,25 +960,51 @@ pub fn log_to_file<T: Debug>(data: T) {
     }
 }
 
-#[derive(Debug, PartialEq, PartialOrd, Eq, Ord)]
+#[derive(Debug, PartialEq, Eq)]
 pub(crate) struct ViewportElement<T> {
     pub(crate) widget: T,
     pub(crate) main_axis_size: u16,
+    pub(crate) hints: LayoutHints,
     pub(crate) truncation: Truncation,
+    pub(crate) block: Option<Block<'static>>,
 }
 
 impl<T> ViewportElement<T> {
+    #[cfg(test)]
     #[must_use]
     pub(crate) fn new(widget: T, main_axis_size: u16, truncation: Truncation) -> Self {
         Self {
             widget,
             main_axis_size,
+            hints: LayoutHints::default(),
             truncation,
+            block: None,
+        }
+    }
+
+    #[must_use]
+    pub(crate) fn from_item(item: BuiltItem<T>, truncation: Truncation) -> Self {
+        Self {
+            widget: item.widget,
+            main_axis_size: item.main_axis_size,
+            hints: item.hints,
+            truncation,
+            block: item.block,
+        }
+    }
+
+    pub(crate) fn into_item(self) -> BuiltItem<T> {
+        BuiltItem {
+            widget: self.widget,
+            main_axis_size: self.main_axis_size,
+            hints: self.hints,
+            block: self.block,
         }
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_return, clippy::useless_vec)]
 mod tests {
     use ratatui::{
         prelude::*,
@@ -486,6 +1012,7 @@ mod tests {
     };
 
     use crate::state::ViewState;
+    use std::{cell::RefCell, rc::Rc};
 
     use super::*;
 
@@ -546,7 +1073,7 @@ mod tests {
             &ListBuilder::new(move |context| {
                 return (TestItem {}, given_sizes[context.index]);
             }),
-            given_item_count,
+            0..given_item_count,
             given_total_size,
             1,
             ScrollAxis::Vertical,
@@ -605,7 +1132,7 @@ mod tests {
             &ListBuilder::new(move |context| {
                 return (TestItem {}, given_sizes[context.index]);
             }),
-            given_item_count,
+            0..given_item_count,
             given_total_size,
             1,
             ScrollAxis::Vertical,
@@ -659,7 +1186,7 @@ mod tests {
             &ListBuilder::new(move |context| {
                 return (TestItem {}, given_sizes[context.index]);
             }),
-            given_item_count,
+            0..given_item_count,
             given_total_size,
             1,
             ScrollAxis::Vertical,
@@ -717,7 +1244,7 @@ mod tests {
             &ListBuilder::new(move |context| {
                 return (TestItem {}, given_sizes[context.index]);
             }),
-            given_item_count,
+            0..given_item_count,
             given_total_size,
             1,
             ScrollAxis::Vertical,
@@ -780,7 +1307,7 @@ mod tests {
             &ListBuilder::new(move |context| {
                 return (TestItem {}, given_sizes[context.index]);
             }),
-            given_item_count,
+            0..given_item_count,
             given_total_size,
             1,
             ScrollAxis::Vertical,
@@ -839,7 +1366,7 @@ mod tests {
             &ListBuilder::new(move |context| {
                 return (TestItem {}, given_sizes[context.index]);
             }),
-            given_item_count,
+            0..given_item_count,
             given_total_size,
             1,
             ScrollAxis::Vertical,
@@ -905,7 +1432,7 @@ mod tests {
             &ListBuilder::new(move |context| {
                 return (TestItem {}, given_sizes[context.index]);
             }),
-            given_item_count,
+            0..given_item_count,
             given_total_size,
             1,
             ScrollAxis::Vertical,
@@ -917,6 +1444,305 @@ mod tests {
         assert_eq!(state.view_state, expected_view_state);
     }
 
+    // From:
+    //
+    // -----
+    // |   | 0 <-
+    // |   |
+    // -----
+    // |   | 1
+    // |   |
+    // -----
+    //
+    // To:
+    //
+    // -----
+    // |   | 1
+    // |   |
+    // -----
+    // |   | 2 <-
+    // |   |
+    // -----
+    #[test]
+    fn snapped_scroll_down() {
+        // given
+        let mut state = ListState {
+            num_elements: 3,
+            selected: Some(2),
+            ..ListState::default()
+        };
+        let given_sizes = vec![2, 2, 2];
+        let given_item_count = given_sizes.len();
+        let given_total_size = 5;
+
+        let expected_view_state = ViewState {
+            offset: 1,
+            first_truncated: 0,
+        };
+        let expected_viewport = HashMap::from([
+            (1, ViewportElement::new(TestItem {}, 2, Truncation::None)),
+            (2, ViewportElement::new(TestItem {}, 2, Truncation::None)),
+        ]);
+
+        // when
+        let viewport = layout_on_viewport_snapped(
+            &mut state,
+            &ListBuilder::new(move |context| {
+                return (TestItem {}, given_sizes[context.index]);
+            }),
+            0..given_item_count,
+            given_total_size,
+            1,
+            ScrollAxis::Vertical,
+            0,
+        );
+
+        // then
+        assert_eq!(viewport, expected_viewport);
+        assert_eq!(state.view_state, expected_view_state);
+    }
+
+    // From:
+    //
+    // -----
+    // |   | 2
+    // |   |
+    // -----
+    // |   | 3 <-
+    // |   |
+    // -----
+    //
+    //
+    // To:
+    //
+    // -----
+    // |   | 1
+    // |   |
+    // -----
+    // |   | 2 <-
+    // |   |
+    // -----
+    // |   | 3
+    // |   |
+    #[test]
+    fn snapped_scroll_padding_top() {
+        // given
+        let view_state = ViewState {
+            offset: 2,
+            first_truncated: 0,
+        };
+        let mut state = ListState {
+            num_elements: 4,
+            selected: Some(2),
+            view_state,
+            ..ListState::default()
+        };
+        let given_sizes = vec![2, 2, 2, 2];
+        let given_item_count = given_sizes.len();
+        let given_total_size = 6;
+
+        let expected_view_state = ViewState {
+            offset: 1,
+            first_truncated: 0,
+        };
+        let expected_viewport = HashMap::from([
+            (1, ViewportElement::new(TestItem {}, 2, Truncation::None)),
+            (2, ViewportElement::new(TestItem {}, 2, Truncation::None)),
+            (3, ViewportElement::new(TestItem {}, 2, Truncation::None)),
+        ]);
+
+        // when
+        let viewport = layout_on_viewport_snapped(
+            &mut state,
+            &ListBuilder::new(move |context| {
+                return (TestItem {}, given_sizes[context.index]);
+            }),
+            0..given_item_count,
+            given_total_size,
+            1,
+            ScrollAxis::Vertical,
+            1,
+        );
+
+        // then
+        assert_eq!(viewport, expected_viewport);
+        assert_eq!(state.view_state, expected_view_state);
+    }
+
+    #[test]
+    fn snapped_item_larger_than_viewport() {
+        // given
+        let mut state = ListState {
+            num_elements: 2,
+            selected: Some(1),
+            ..ListState::default()
+        };
+        let given_sizes = vec![2, 6];
+        let given_item_count = given_sizes.len();
+        let given_total_size = 4;
+
+        let expected_view_state = ViewState {
+            offset: 1,
+            first_truncated: 0,
+        };
+        let expected_viewport =
+            HashMap::from([(1, ViewportElement::new(TestItem {}, 6, Truncation::Bot(2)))]);
+
+        // when
+        let viewport = layout_on_viewport_snapped(
+            &mut state,
+            &ListBuilder::new(move |context| {
+                return (TestItem {}, given_sizes[context.index]);
+            }),
+            0..given_item_count,
+            given_total_size,
+            1,
+            ScrollAxis::Vertical,
+            0,
+        );
+
+        // then
+        assert_eq!(viewport, expected_viewport);
+        assert_eq!(state.view_state, expected_view_state);
+    }
+
+    #[test]
+    fn snapped_zero_size_items_next_to_large_item() {
+        // given
+        let mut state = ListState {
+            num_elements: 4,
+            selected: Some(0),
+            ..ListState::default()
+        };
+        let given_sizes = vec![5, 0, 1, 1];
+        let given_item_count = given_sizes.len();
+        let given_total_size = 1;
+
+        let expected_view_state = ViewState {
+            offset: 0,
+            first_truncated: 0,
+        };
+        let expected_viewport =
+            HashMap::from([(0, ViewportElement::new(TestItem {}, 5, Truncation::Bot(4)))]);
+
+        // when
+        let viewport = layout_on_viewport_snapped(
+            &mut state,
+            &ListBuilder::new(move |context| {
+                return (TestItem {}, given_sizes[context.index]);
+            }),
+            0..given_item_count,
+            given_total_size,
+            1,
+            ScrollAxis::Vertical,
+            1,
+        );
+
+        // then
+        assert_eq!(viewport, expected_viewport);
+        assert_eq!(state.view_state, expected_view_state);
+    }
+
+    // -----
+    // |   | 0
+    // -----
+    // |   | 1 <-
+    // |   |
+    // -----
+    // |   | 2
+    // |   |
+    #[test]
+    fn centered() {
+        // given
+        let mut state = ListState {
+            num_elements: 3,
+            selected: Some(1),
+            ..ListState::default()
+        };
+        let given_sizes = vec![2, 2, 2];
+        let given_item_count = given_sizes.len();
+        let given_total_size = 5;
+
+        let expected_view_state = ViewState {
+            offset: 0,
+            first_truncated: 1,
+        };
+        let expected_viewport = HashMap::from([
+            (0, ViewportElement::new(TestItem {}, 2, Truncation::Top(1))),
+            (1, ViewportElement::new(TestItem {}, 2, Truncation::None)),
+            (2, ViewportElement::new(TestItem {}, 2, Truncation::None)),
+        ]);
+
+        // when
+        let viewport = layout_on_viewport_centered(
+            &mut state,
+            &ListBuilder::new(move |context| {
+                return (TestItem {}, given_sizes[context.index]);
+            }),
+            0..given_item_count,
+            given_total_size,
+            1,
+            ScrollAxis::Vertical,
+        );
+
+        // then
+        assert_eq!(viewport, expected_viewport);
+        assert_eq!(state.view_state, expected_view_state);
+    }
+
+    #[test]
+    fn measure_without_building() {
+        // given
+        let mut state = ListState {
+            num_elements: 10,
+            selected: Some(5),
+            ..ListState::default()
+        };
+        let built = Rc::new(RefCell::new(Vec::new()));
+        let builder = ListBuilder::with_measure(|_| 2, {
+            let built = Rc::clone(&built);
+            move |context| {
+                built.borrow_mut().push(context.index);
+                (TestItem {}, 2)
+            }
+        });
+
+        // when
+        let viewport =
+            layout_on_viewport(&mut state, &builder, 0..10, 4, 1, ScrollAxis::Vertical, 2);
+
+        // then
+        assert!(viewport.contains_key(&5));
+        for index in [2, 3, 4, 7, 8, 9] {
+            assert!(!built.borrow().contains(&index));
+        }
+    }
+
+    #[test]
+    fn test_calculate_effective_scroll_padding_near_both_edges() {
+        let mut state = ListState::default();
+        let given_sizes = vec![1, 1, 1];
+        let item_count = 3;
+        let scroll_padding = 3;
+
+        let builder = ListBuilder::new(move |context| {
+            return (TestItem {}, given_sizes[context.index]);
+        });
+
+        let scroll_padding = calculate_effective_scroll_padding(
+            &mut state,
+            &builder,
+            0..item_count,
+            1,
+            ScrollAxis::Vertical,
+            scroll_padding,
+        );
+
+        assert_eq!(scroll_padding.get(0), 0);
+        assert_eq!(scroll_padding.get(1), 1);
+        assert_eq!(scroll_padding.get(2), 0);
+    }
+
     #[test]
     fn test_calculate_effective_scroll_padding() {
         let mut state = ListState::default();
@@ -931,16 +1757,16 @@ mod tests {
         let scroll_padding = calculate_effective_scroll_padding(
             &mut state,
             &builder,
-            item_count,
+            0..item_count,
             1,
             ScrollAxis::Vertical,
             scroll_padding,
         );
 
-        assert_eq!(*scroll_padding.get(&0).unwrap(), 0);
-        assert_eq!(*scroll_padding.get(&1).unwrap(), 2);
-        assert_eq!(*scroll_padding.get(&2).unwrap(), 3);
-        assert_eq!(*scroll_padding.get(&3).unwrap(), 2);
-        assert_eq!(*scroll_padding.get(&4).unwrap(), 0);
+        assert_eq!(scroll_padding.get(0), 0);
+        assert_eq!(scroll_padding.get(1), 2);
+        assert_eq!(scroll_padding.get(2), 3);
+        assert_eq!(scroll_padding.get(3), 2);
+        assert_eq!(scroll_padding.get(4), 0);
     }
 }
@@ -1,11 +1,29 @@
+use std::{collections::HashMap, ops::Range};
+
 use ratatui::{
     buffer::Buffer,
-    layout::{Position, Rect},
+    layout::{Position, Rect, Size},
     style::{Style, Styled},
-    widgets::{block::BlockExt, Block, StatefulWidget, Widget},
+    text::Span,
+    widgets::{
+        block::BlockExt, Block, Clear, HighlightSpacing, Scrollbar, ScrollbarState, StatefulWidget,
+        Widget,
+    },
 };
 
-use crate::{utils::layout_on_viewport, ListState};
+use crate::{
+    clipped::{Clip, ClippedWidget},
+    masonry::Placement,
+    overflow::{OverflowIndicators, OverflowPlacement, TruncationMarker},
+    state::{Direction, ViewState},
+    styles::{ItemStatus, ListStyles},
+    utils::{
+        find_sticky, layout_on_viewport, layout_on_viewport_centered, layout_on_viewport_snapped,
+        layout_pinned, restore_anchor, scroll_position, scroll_to_fraction, to_usize,
+        ViewportElement,
+    },
+    ListState,
+};
 
 /// A struct representing a list view.
 /// The widget displays a scrollable list of items.
@@ -32,8 +50,72 @@ pub struct ListView<'a, T> {
     /// Whether infinite scrolling is enabled or not.
     /// Disabled by default.
     pub(crate) infinite_scrolling: bool,
+
+    /// Whether the list is laid out in reverse, i.e. the first item
+    /// is anchored at the bottom (or right) of the viewport.
+    /// Disabled by default.
+    pub(crate) reverse: bool,
+
+    /// The alignment of the items along the main axis, if they do not fill the
+    /// viewport.
+    pub(crate) alignment: MainAxisAlignment,
+
+    /// The alignment of items that are smaller than the list along the cross axis.
+    pub(crate) cross_axis_alignment: CrossAxisAlignment,
+
+    /// Whether only whole items are shown on the viewport.
+    /// Disabled by default.
+    pub(crate) snap: bool,
+
+    /// Whether the selected item is always centered on the viewport.
+    /// Disabled by default.
+    pub(crate) carousel: bool,
+
+    /// The number of items at the start of the list that are pinned.
+    pub(crate) pinned_leading: usize,
+
+    /// The number of items at the end of the list that are pinned.
+    pub(crate) pinned_trailing: usize,
+
+    /// The estimated size of items that have not been laid out yet.
+    pub(crate) estimated_item_size: Option<u16>,
+
+    /// The size of the items along the cross axis, if they can be larger than
+    /// the list.
+    pub(crate) virtual_cross_axis_size: Option<u16>,
+
+    /// The number of columns in masonry mode.
+    /// Disabled by default.
+    pub(crate) masonry: Option<usize>,
+
+    /// The scrollbar shown inside the block.
+    pub(crate) scrollbar: Option<Scrollbar<'a>>,
+
+    /// The markers for items before and after the viewport.
+    pub(crate) overflow_indicators: Option<OverflowIndicators>,
+
+    /// The marker on the cut edge of partially visible items.
+    pub(crate) truncation_marker: Option<TruncationMarker>,
+
+    /// Renders the placeholder if the list is empty.
+    pub(crate) empty_placeholder: Option<Box<EmptyPlaceholder<'a>>>,
+
+    /// The styles patched onto the items after they are rendered.
+    pub(crate) styles: ListStyles,
+
+    /// The symbol drawn next to the selected item.
+    pub(crate) highlight_symbol: Option<&'a str>,
+
+    /// The style patched onto the selected item after it is rendered.
+    pub(crate) highlight_style: Style,
+
+    /// When space is reserved for the highlight symbol.
+    pub(crate) highlight_spacing: HighlightSpacing,
 }
 
+/// A type alias for the closure that renders the placeholder of an empty list.
+type EmptyPlaceholder<'a> = dyn FnOnce(Rect, &mut Buffer) + 'a;
+
 impl<'a, T> ListView<'a, T> {
     /// Creates a new `ListView` with a builder an item count.
     #[must_use]
@@ -46,6 +128,24 @@ impl<'a, T> ListView<'a, T> {
             block: None,
             scroll_padding: 0,
             infinite_scrolling: true,
+            reverse: false,
+            alignment: MainAxisAlignment::Start,
+            cross_axis_alignment: CrossAxisAlignment::Start,
+            snap: false,
+            carousel: false,
+            pinned_leading: 0,
+            pinned_trailing: 0,
+            estimated_item_size: None,
+            virtual_cross_axis_size: None,
+            masonry: None,
+            scrollbar: None,
+            overflow_indicators: None,
+            truncation_marker: None,
+            empty_placeholder: None,
+            styles: ListStyles::default(),
+            highlight_symbol: None,
+            highlight_style: Style::default(),
+            highlight_spacing: HighlightSpacing::default(),
         }
     }
 
@@ -89,12 +189,325 @@ impl<'a, T> ListView<'a, T> {
         self
     }
 
+    /// Sets a fixed size along the main axis for all items, including their
+    /// margins. The size returned by the builder is ignored.
+    ///
+    /// With a fixed size, the layout is computed arithmetically and the builder
+    /// is only called for the items on the viewport. This keeps rendering fast
+    /// for lists with millions of items.
+    #[must_use]
+    pub fn fixed_item_size(mut self, size: u16) -> Self {
+        self.builder.fixed_main_axis_size = Some(size.max(1));
+        self
+    }
+
+    /// Sets the estimated size along the main axis of items that have not been
+    /// laid out yet.
+    ///
+    /// Jumping to a fraction of the list, see [`ListState::scroll_to_fraction`],
+    /// needs the size of all items. With an estimate, only the items that are
+    /// laid out are measured, the estimate is used for all other items. The
+    /// measurements are refined as the list is scrolled. The selected item stays
+    /// in place when the measurements change.
+    #[must_use]
+    pub fn estimated_item_size(mut self, size: u16) -> Self {
+        self.estimated_item_size = Some(size);
+        self
+    }
+
+    /// Renders a placeholder, e.g. a dim skeleton row, for items that are not
+    /// ready yet, such as items whose data is still loading.
+    ///
+    /// `is_ready` is called with the index of an item. For items that are not
+    /// ready, `placeholder` is built instead of the item and assumed to have
+    /// the size `size` along the main axis. When the item becomes ready and its
+    /// size differs, the items before the selected item are moved, so that the
+    /// selected item keeps its position on the viewport. The cached sizes of
+    /// the scrollbar are not updated automatically, call [`ListState::invalidate`]
+    /// with the items that became ready.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{style::Stylize, text::Line};
+    /// use tui_widget_list::{ListBuilder, ListView};
+    ///
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("Item {0}", context.index)), 2));
+    /// let list = ListView::new(builder, 20)
+    ///     .loading_placeholder(|index| index < 10, 1, |_| Line::from("░░░░░░").dim());
+    /// ```
+    #[must_use]
+    pub fn loading_placeholder<R, P>(mut self, is_ready: R, size: u16, placeholder: P) -> Self
+    where
+        R: Fn(usize) -> bool + 'static,
+        P: Fn(&ListBuildContext) -> T + 'static,
+    {
+        self.builder.placeholder = Some(LoadingPlaceholder {
+            is_ready: Box::new(is_ready),
+            main_axis_size: size,
+            build: Box::new(placeholder),
+        });
+        self
+    }
+
+    /// Wraps each item in a block, e.g. a card with borders. The closure receives
+    /// the context of the item, so that the block can be styled depending on
+    /// the selection.
+    ///
+    /// The builder returns the size of the content, the thickness of the block
+    /// is added by the list. Likewise, [`ListBuildContext::cross_axis_size`] is
+    /// the size of the content inside the block.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{style::{Color, Style}, text::Line, widgets::Block};
+    /// use tui_widget_list::{ListBuilder, ListView};
+    ///
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("Item {0}", context.index)), 1));
+    /// let list = ListView::new(builder, 20).item_block(|context| {
+    ///     let color = if context.is_selected { Color::Yellow } else { Color::Gray };
+    ///     Block::bordered().border_style(Style::new().fg(color))
+    /// });
+    /// ```
+    #[must_use]
+    pub fn item_block<F>(mut self, block: F) -> Self
+    where
+        F: Fn(&ListBuildContext) -> Block<'static> + 'static,
+    {
+        self.builder.item_block = Some(Box::new(block));
+        self
+    }
+
+    /// Sets the size of the virtual area along the cross axis in which the
+    /// items are laid out, e.g. its width in a vertical list. If it is larger
+    /// than the list, the items can be scrolled along the cross axis with
+    /// [`ListState::scroll_left`] and [`ListState::scroll_right`].
+    ///
+    /// The builder receives this size as [`ListBuildContext::cross_axis_size`].
+    /// Unlike [`ItemLayout::cross_axis_size`], which lets single items be
+    /// narrower than the list, this size applies to all items.
+    #[must_use]
+    pub fn virtual_cross_axis_size(mut self, size: u16) -> Self {
+        self.virtual_cross_axis_size = Some(size);
+        self
+    }
+
+    /// Lays out the items in `columns` columns of equal width, each item is
+    /// placed in the column that is currently the shortest. The list scrolls
+    /// vertically.
+    ///
+    /// The selection follows the index of the items with [`ListState::next`]
+    /// and [`ListState::previous`], and moves within a column with
+    /// [`ListState::next_in_column`] and [`ListState::previous_in_column`].
+    ///
+    /// Items are measured up to the end of the viewport and only the visible
+    /// items are built. The sizes are measured with the items not being selected.
+    ///
+    /// The highlight symbol, the scrollbar and the overflow indicators are not
+    /// shown in masonry mode. The highlight style is applied to the selected item.
+    #[must_use]
+    pub fn masonry(mut self, columns: usize) -> Self {
+        self.masonry = Some(columns.max(1));
+        self
+    }
+
+    /// Shows a scrollbar inside the block. The scrollbar occupies the last
+    /// column of vertical lists and the last row of horizontal lists.
+    ///
+    /// Ratatui's [`Scrollbar`] does not expose its orientation, so the list
+    /// cannot place the scrollbar based on it. Use
+    /// [`ScrollbarOrientation::VerticalRight`] for vertical lists and
+    /// [`ScrollbarOrientation::HorizontalBottom`] for horizontal lists. The
+    /// orientations `VerticalLeft` and `HorizontalTop` are not supported, they
+    /// are drawn in the last column or row as well. An orientation that does not
+    /// match the scroll axis is not supported either.
+    ///
+    /// [`ScrollbarOrientation::VerticalRight`]: ratatui::widgets::ScrollbarOrientation::VerticalRight
+    /// [`ScrollbarOrientation::HorizontalBottom`]: ratatui::widgets::ScrollbarOrientation::HorizontalBottom
+    ///
+    /// The content length and the position of the scrollbar are measured in
+    /// cells, which requires the sizes of all items. They are cached in the
+    /// [`ListState`]. For long lists, consider [`ListView::fixed_item_size`] or
+    /// [`ListView::estimated_item_size`]. The scrollbar is not shown in masonry
+    /// mode, see [`ListView::masonry`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{Scrollbar, ScrollbarOrientation};
+    /// use tui_widget_list::{ListBuilder, ListView};
+    ///
+    /// let builder = ListBuilder::new(|context| (format!("Item {0}", context.index), 1));
+    /// let list = ListView::new(builder, 20).scrollbar(Scrollbar::new(ScrollbarOrientation::VerticalRight));
+    /// ```
+    #[must_use]
+    pub fn scrollbar(mut self, scrollbar: Scrollbar<'a>) -> Self {
+        self.scrollbar = Some(scrollbar);
+        self
+    }
+
+    /// Shows markers such as `▲ 12 more` or `▼ 30 more` if there are items
+    /// before or after the viewport. See [`OverflowIndicators`]. The markers are
+    /// not shown in masonry mode, see [`ListView::masonry`].
+    #[must_use]
+    pub fn overflow_indicators(mut self, indicators: OverflowIndicators) -> Self {
+        self.overflow_indicators = Some(indicators);
+        self
+    }
+
+    /// Marks the cut edge of items that are only partially visible, by dimming
+    /// it or drawing a symbol such as `…`. See [`TruncationMarker`].
+    #[must_use]
+    pub fn truncation_marker(mut self, marker: TruncationMarker) -> Self {
+        self.truncation_marker = Some(marker);
+        self
+    }
+
+    /// Sets a placeholder that is shown if the list has no items. The closure
+    /// receives the area inside the block and returns the widget to render.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{text::Line, widgets::Paragraph};
+    /// use tui_widget_list::{ListBuilder, ListView};
+    ///
+    /// let builder = ListBuilder::new(|context| (Line::from(format!("Item {0}", context.index)), 1));
+    /// let list = ListView::new(builder, 0).empty_placeholder(|area| {
+    ///     let text = if area.width < 10 { "Empty" } else { "No results" };
+    ///     Paragraph::new(text).centered()
+    /// });
+    /// ```
+    #[must_use]
+    pub fn empty_placeholder<F, W>(mut self, placeholder: F) -> Self
+    where
+        F: FnOnce(Rect) -> W + 'a,
+        W: Widget,
+    {
+        self.empty_placeholder = Some(Box::new(move |area, buf| {
+            placeholder(area).render(area, buf);
+        }));
+        self
+    }
+
+    /// Sets the styles that are patched onto the items after they are rendered,
+    /// e.g. alternating styles for even and odd items. See [`ListStyles`].
+    #[must_use]
+    pub fn styles(mut self, styles: ListStyles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Sets the symbol that is drawn next to the selected item. Space for the
+    /// symbol is reserved on the left of vertical lists and on top of
+    /// horizontal lists, see [`ListView::highlight_spacing`]. The symbol is not
+    /// shown in masonry mode, see [`ListView::masonry`].
+    #[must_use]
+    pub fn highlight_symbol(mut self, highlight_symbol: &'a str) -> Self {
+        self.highlight_symbol = Some(highlight_symbol);
+        self
+    }
+
+    /// Sets the style of the selected item. It is patched onto the item after
+    /// the widget is rendered, and onto the space of the highlight symbol.
+    #[must_use]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Sets when space is reserved for the highlight symbol. By default, only
+    /// if an item is selected. No space is reserved in masonry mode.
+    #[must_use]
+    pub fn highlight_spacing(mut self, highlight_spacing: HighlightSpacing) -> Self {
+        self.highlight_spacing = highlight_spacing;
+        self
+    }
+
     /// Specify whether infinite scrolling should be enabled or not.
     #[must_use]
     pub fn infinite_scrolling(mut self, infinite_scrolling: bool) -> Self {
         self.infinite_scrolling = infinite_scrolling;
         self
     }
+
+    /// Specify whether the list should be laid out in reverse.
+    ///
+    /// In reverse mode the first item sits at the bottom of the viewport (or at
+    /// the right for horizontal lists) and the following items stack upwards.
+    /// This is useful for chat transcripts or logs, where the newest item is
+    /// item `0` and should stick to the bottom edge. Scroll padding and
+    /// truncation are mirrored accordingly.
+    #[must_use]
+    pub fn reverse(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
+    /// Set the alignment of the items along the main axis.
+    ///
+    /// The alignment only takes effect if all items fit onto the viewport,
+    /// otherwise the items are packed at the start of the list.
+    #[must_use]
+    pub fn align(mut self, alignment: MainAxisAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Set the alignment of the items along the cross axis.
+    ///
+    /// Only applies to items that specify their cross axis size, see
+    /// [`ListBuilder::with_cross_axis_size`].
+    #[must_use]
+    pub fn cross_axis_alignment(mut self, alignment: CrossAxisAlignment) -> Self {
+        self.cross_axis_alignment = alignment;
+        self
+    }
+
+    /// Specify whether only whole items should be shown on the viewport.
+    ///
+    /// If enabled, the list scrolls by entire items and never shows partially
+    /// visible items at the edges of the viewport. The space that is left after
+    /// the last item remains empty. Items that are larger than the viewport are
+    /// shown on their own and clipped at the bottom.
+    #[must_use]
+    pub fn snap(mut self, snap: bool) -> Self {
+        self.snap = snap;
+        self
+    }
+
+    /// Specify whether the selected item should always be centered on the viewport.
+    ///
+    /// If enabled, the selected item is rendered in the middle of the viewport
+    /// with its neighbours before and after it, similar to a wheel picker. Blank
+    /// space is left before the first and after the last item. Scroll padding,
+    /// snapping and the main axis alignment have no effect in this mode.
+    #[must_use]
+    pub fn carousel(mut self, carousel: bool) -> Self {
+        self.carousel = carousel;
+        self
+    }
+
+    /// Pins the first `count` items to the start of the viewport.
+    ///
+    /// Pinned items stay visible regardless of the scroll position, e.g. a
+    /// group of favorites. The remaining items scroll between the pinned items.
+    #[must_use]
+    pub fn pinned_leading(mut self, count: usize) -> Self {
+        self.pinned_leading = count;
+        self
+    }
+
+    /// Pins the last `count` items to the end of the viewport.
+    ///
+    /// Pinned items stay visible regardless of the scroll position, e.g. a
+    /// "Load more" item. The remaining items scroll between the pinned items.
+    #[must_use]
+    pub fn pinned_trailing(mut self, count: usize) -> Self {
+        self.pinned_trailing = count;
+        self
+    }
 }
 
 impl<T> Styled for ListView<'_, T> {
@@ -136,27 +549,382 @@ pub struct ListBuildContext {
 }
 
 /// A type alias for the closure.
-type ListBuilderClosure<T> = dyn Fn(&ListBuildContext) -> (T, u16);
+type ListBuilderClosure<T> = dyn Fn(&ListBuildContext) -> ItemLayout<T>;
+
+/// A type alias for the closure that measures the size of an item.
+type ListMeasureClosure = dyn Fn(&ListBuildContext) -> u16;
+
+/// A type alias for the function that renders the visible part of an item.
+type ClipRenderer<T> = fn(T, Rect, Clip, &mut Buffer);
 
 /// The builder to for constructing list elements in a `ListView<T>`
 pub struct ListBuilder<T> {
     closure: Box<ListBuilderClosure<T>>,
+    measure: Option<Box<ListMeasureClosure>>,
+    fixed_main_axis_size: Option<u16>,
+    render_clipped: Option<ClipRenderer<T>>,
+    placeholder: Option<LoadingPlaceholder<T>>,
+    item_block: Option<Box<ListBlockClosure>>,
+}
+
+/// The placeholder for items that are not ready, see [`ListView::loading_placeholder`].
+struct LoadingPlaceholder<T> {
+    is_ready: Box<dyn Fn(usize) -> bool>,
+    main_axis_size: u16,
+    build: Box<ListPlaceholderClosure<T>>,
 }
 
+/// A type alias for the closure that builds the block around an item.
+type ListBlockClosure = dyn Fn(&ListBuildContext) -> Block<'static>;
+
+/// A type alias for the closure that builds a placeholder.
+type ListPlaceholderClosure<T> = dyn Fn(&ListBuildContext) -> T;
+
 impl<T> ListBuilder<T> {
-    /// Creates a new `ListBuilder` taking a closure as a parameter
-    pub fn new<F>(closure: F) -> Self
+    /// Creates a new `ListBuilder` taking a closure as a parameter.
+    ///
+    /// The closure returns either a tuple of the widget and its size along the
+    /// main axis, or an [`ItemLayout`] with additional layout information.
+    pub fn new<F, R>(closure: F) -> Self
+    where
+        F: Fn(&ListBuildContext) -> R + 'static,
+        R: Into<ItemLayout<T>>,
+    {
+        ListBuilder {
+            closure: Box::new(move |context| closure(context).into()),
+            measure: None,
+            fixed_main_axis_size: None,
+            render_clipped: None,
+            placeholder: None,
+            item_block: None,
+        }
+    }
+
+    /// Creates a new `ListBuilder` with separate closures to measure and to
+    /// build the items.
+    ///
+    /// The list often needs to know the size of items that are not rendered,
+    /// e.g. to apply the scroll padding. With `ListBuilder::new` the whole item
+    /// is constructed just to learn its size. Here, the cheap `measure` closure
+    /// is used instead, and `build` is only called for items that are rendered.
+    ///
+    /// `measure` must return the size of the item along the main axis including
+    /// its margins, i.e. the same size that `build` returns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::text::Line;
+    /// use tui_widget_list::ListBuilder;
+    ///
+    /// let builder = ListBuilder::with_measure(
+    ///     |context| if context.is_selected { 2 } else { 1 },
+    ///     |context| {
+    ///         let size = if context.is_selected { 2 } else { 1 };
+    ///         (Line::from(format!("Item {0}", context.index)), size)
+    ///     },
+    /// );
+    /// ```
+    pub fn with_measure<M, F, R>(measure: M, build: F) -> Self
     where
-        F: Fn(&ListBuildContext) -> (T, u16) + 'static,
+        M: Fn(&ListBuildContext) -> u16 + 'static,
+        F: Fn(&ListBuildContext) -> R + 'static,
+        R: Into<ItemLayout<T>>,
     {
         ListBuilder {
-            closure: Box::new(closure),
+            closure: Box::new(move |context| build(context).into()),
+            measure: Some(Box::new(measure)),
+            fixed_main_axis_size: None,
+            render_clipped: None,
+            placeholder: None,
+            item_block: None,
         }
     }
 
+    /// Creates a new `ListBuilder` whose closure additionally returns the size
+    /// of the widget along the cross axis.
+    ///
+    /// Items that are narrower than the list are positioned according to
+    /// [`ListView::cross_axis_alignment`]. The remaining space is filled with the
+    /// base style of the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::text::Line;
+    /// use tui_widget_list::ListBuilder;
+    ///
+    /// let builder = ListBuilder::with_cross_axis_size(|context| {
+    ///     let text = format!("Item {0}", context.index);
+    ///     let cross_axis_size = text.len() as u16;
+    ///
+    ///     (Line::from(text), 1, cross_axis_size)
+    /// });
+    /// ```
+    pub fn with_cross_axis_size<F>(closure: F) -> Self
+    where
+        F: Fn(&ListBuildContext) -> (T, u16, u16) + 'static,
+    {
+        ListBuilder::new(move |context| {
+            let (widget, main_axis_size, cross_axis_size) = closure(context);
+            ItemLayout::new(widget, main_axis_size).cross_axis_size(cross_axis_size)
+        })
+    }
+
+    /// Renders partially visible items with [`ClippedWidget::render_clipped`],
+    /// instead of rendering them fully into a hidden buffer.
+    #[must_use]
+    pub fn clipped(mut self) -> Self
+    where
+        T: ClippedWidget,
+    {
+        self.render_clipped = Some(T::render_clipped);
+        self
+    }
+
     /// Method to call the stored closure.
-    pub(crate) fn call_closure(&self, context: &ListBuildContext) -> (T, u16) {
-        (self.closure)(context)
+    /// If all items have a fixed size, it overrides the size of the built item.
+    pub(crate) fn call_closure(&self, context: &ListBuildContext) -> BuiltItem<T> {
+        let block = self.call_item_block(context);
+        let (main_axis_thickness, cross_axis_thickness) =
+            block_thickness(block.as_ref(), context.scroll_axis);
+        let context = &ListBuildContext {
+            cross_axis_size: context.cross_axis_size.saturating_sub(cross_axis_thickness),
+            ..*context
+        };
+        let mut item: BuiltItem<T> = match self.pending_placeholder(context.index) {
+            Some(placeholder) => {
+                ItemLayout::new((placeholder.build)(context), placeholder.main_axis_size).into()
+            }
+            None => (self.closure)(context).into(),
+        };
+        item.main_axis_size = item.main_axis_size.saturating_add(main_axis_thickness);
+        item.hints.cross_axis_size = item
+            .hints
+            .cross_axis_size
+            .map(|size| size.saturating_add(cross_axis_thickness));
+        if let Some(size) = self.fixed_main_axis_size {
+            item.main_axis_size = size;
+        }
+        item.block = block;
+        item
+    }
+
+    /// Method to call the stored measure closure. Returns `None` if the builder
+    /// has no measure closure and the items have no fixed size.
+    pub(crate) fn call_measure(&self, context: &ListBuildContext) -> Option<u16> {
+        if self.fixed_main_axis_size.is_some() {
+            return self.fixed_main_axis_size;
+        }
+        let (main_axis_thickness, cross_axis_thickness) =
+            block_thickness(self.call_item_block(context).as_ref(), context.scroll_axis);
+        let context = &ListBuildContext {
+            cross_axis_size: context.cross_axis_size.saturating_sub(cross_axis_thickness),
+            ..*context
+        };
+        self.pending_placeholder(context.index)
+            .map(|placeholder| placeholder.main_axis_size)
+            .or_else(|| self.measure.as_ref().map(|measure| measure(context)))
+            .map(|size| size.saturating_add(main_axis_thickness))
+    }
+
+    /// Returns the block around the item, if any.
+    fn call_item_block(&self, context: &ListBuildContext) -> Option<Block<'static>> {
+        self.item_block
+            .as_ref()
+            .map(|item_block| item_block(context))
+    }
+
+    /// Returns the placeholder if the item at `index` is not ready.
+    fn pending_placeholder(&self, index: usize) -> Option<&LoadingPlaceholder<T>> {
+        self.placeholder
+            .as_ref()
+            .filter(|placeholder| !(placeholder.is_ready)(index))
+    }
+
+    /// Whether the builder renders placeholders for items that are not ready.
+    pub(crate) fn has_placeholder(&self) -> bool {
+        self.placeholder.is_some()
+    }
+
+    /// Returns the size of all items along the main axis, if it is fixed.
+    pub(crate) fn fixed_main_axis_size(&self) -> Option<u16> {
+        self.fixed_main_axis_size
+    }
+
+    /// Returns the size of an item along the main axis. Uses the measure closure
+    /// if available, otherwise the item is built.
+    pub(crate) fn main_axis_size(&self, context: &ListBuildContext) -> u16 {
+        self.call_measure(context)
+            .unwrap_or_else(|| self.call_closure(context).main_axis_size)
+    }
+}
+
+/// A widget returned by the [`ListBuilder`] together with information on how
+/// to lay it out.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::text::Line;
+/// use tui_widget_list::{ItemLayout, ListBuilder};
+///
+/// let builder = ListBuilder::new(|context| {
+///     let item = Line::from(format!("Item {0}", context.index));
+///
+///     // Every fifth item is a header that can not be selected.
+///     let is_header = context.index % 5 == 0;
+///
+///     ItemLayout::new(item, 1)
+///         .margin(u16::from(is_header), 0)
+///         .selectable(!is_header)
+///         .sticky(is_header)
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemLayout<T> {
+    /// The widget.
+    pub widget: T,
+
+    /// The size of the widget along the main axis, excluding the margins.
+    pub main_axis_size: u16,
+
+    /// The space before the widget along the main axis.
+    pub margin_leading: u16,
+
+    /// The space after the widget along the main axis.
+    pub margin_trailing: u16,
+
+    /// The size of the widget along the cross axis. If `None`, the widget
+    /// spans the full cross axis of the list.
+    pub cross_axis_size: Option<u16>,
+
+    /// The alignment of the widget along the cross axis. If `None`, the
+    /// alignment of the list is used.
+    pub cross_axis_alignment: Option<CrossAxisAlignment>,
+
+    /// Whether the item can be selected. Items that can not be selected are
+    /// skipped when navigating the list. True by default.
+    pub selectable: bool,
+
+    /// Whether the item sticks to the start of the viewport once it is scrolled
+    /// past, until the next sticky item pushes it out. Useful for section headers.
+    /// The list only looks for sticky items once one of them has been shown.
+    /// False by default.
+    pub sticky: bool,
+}
+
+impl<T> ItemLayout<T> {
+    /// Creates a new `ItemLayout` with a widget and its size along the main axis.
+    #[must_use]
+    pub fn new(widget: T, main_axis_size: u16) -> Self {
+        Self {
+            widget,
+            main_axis_size,
+            margin_leading: 0,
+            margin_trailing: 0,
+            cross_axis_size: None,
+            cross_axis_alignment: None,
+            selectable: true,
+            sticky: false,
+        }
+    }
+
+    /// Set the space before and after the widget along the main axis.
+    #[must_use]
+    pub fn margin(mut self, leading: u16, trailing: u16) -> Self {
+        self.margin_leading = leading;
+        self.margin_trailing = trailing;
+        self
+    }
+
+    /// Set the size of the widget along the cross axis.
+    #[must_use]
+    pub fn cross_axis_size(mut self, cross_axis_size: u16) -> Self {
+        self.cross_axis_size = Some(cross_axis_size);
+        self
+    }
+
+    /// Set the alignment of the widget along the cross axis.
+    #[must_use]
+    pub fn cross_axis_alignment(mut self, alignment: CrossAxisAlignment) -> Self {
+        self.cross_axis_alignment = Some(alignment);
+        self
+    }
+
+    /// Specify whether the item can be selected.
+    #[must_use]
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
+    /// Specify whether the item sticks to the start of the viewport.
+    #[must_use]
+    pub fn sticky(mut self, sticky: bool) -> Self {
+        self.sticky = sticky;
+        self
+    }
+}
+
+impl<T> From<(T, u16)> for ItemLayout<T> {
+    fn from((widget, main_axis_size): (T, u16)) -> Self {
+        Self::new(widget, main_axis_size)
+    }
+}
+
+/// A widget constructed by the `ListBuilder` together with its size.
+pub(crate) struct BuiltItem<T> {
+    pub(crate) widget: T,
+    /// The size along the main axis, including the margins and the block.
+    pub(crate) main_axis_size: u16,
+    pub(crate) hints: LayoutHints,
+    /// The block around the item, see [`ListView::item_block`].
+    pub(crate) block: Option<Block<'static>>,
+}
+
+impl<T> From<ItemLayout<T>> for BuiltItem<T> {
+    fn from(layout: ItemLayout<T>) -> Self {
+        Self {
+            widget: layout.widget,
+            main_axis_size: layout
+                .main_axis_size
+                .saturating_add(layout.margin_leading)
+                .saturating_add(layout.margin_trailing),
+            hints: LayoutHints {
+                margin_leading: layout.margin_leading,
+                margin_trailing: layout.margin_trailing,
+                cross_axis_size: layout.cross_axis_size,
+                cross_axis_alignment: layout.cross_axis_alignment,
+                selectable: layout.selectable,
+                sticky: layout.sticky,
+            },
+            block: None,
+        }
+    }
+}
+
+/// The layout information of an item apart from its size along the main axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct LayoutHints {
+    pub(crate) margin_leading: u16,
+    pub(crate) margin_trailing: u16,
+    pub(crate) cross_axis_size: Option<u16>,
+    pub(crate) cross_axis_alignment: Option<CrossAxisAlignment>,
+    pub(crate) selectable: bool,
+    pub(crate) sticky: bool,
+}
+
+impl Default for LayoutHints {
+    fn default() -> Self {
+        Self {
+            margin_leading: 0,
+            margin_trailing: 0,
+            cross_axis_size: None,
+            cross_axis_alignment: None,
+            selectable: true,
+            sticky: false,
+        }
     }
 }
 
@@ -171,10 +939,103 @@ pub enum ScrollAxis {
     Horizontal,
 }
 
+/// The alignment of the items along the main axis of a list.
+///
+/// The alignment is only applied if all items fit onto the viewport.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MainAxisAlignment {
+    /// Packs the items at the start of the list. This is the default.
+    #[default]
+    Start,
+
+    /// Centers the items.
+    Center,
+
+    /// Packs the items at the end of the list.
+    End,
+
+    /// Distributes the free space evenly between the items. The first and
+    /// the last item are placed at the edges of the viewport.
+    SpaceBetween,
+
+    /// Distributes the free space evenly between the items and the edges of
+    /// the viewport.
+    SpaceEvenly,
+}
+
+impl MainAxisAlignment {
+    /// Returns the position of each item along the main axis, given the sizes
+    /// of the items and the total available size.
+    pub(crate) fn positions(self, sizes: &[u16], available_size: u16) -> Vec<u16> {
+        let content_size = sizes
+            .iter()
+            .fold(0u16, |acc, size| acc.saturating_add(*size));
+        let free_space = available_size.saturating_sub(content_size);
+
+        // The position of the first item and the number of gaps that share
+        // the free space.
+        let item_count = u16::try_from(sizes.len()).unwrap_or(u16::MAX);
+        let (mut pos, num_gaps) = match self {
+            Self::Start => (0, 0),
+            Self::Center => (free_space / 2, 0),
+            Self::End => (free_space, 0),
+            Self::SpaceBetween => (0, item_count.saturating_sub(1)),
+            Self::SpaceEvenly => (0, item_count.saturating_add(1)),
+        };
+
+        // If the free space can not be divided evenly, the first gaps
+        // receive an additional cell.
+        let mut gaps =
+            (0..num_gaps).map(|n| free_space / num_gaps + u16::from(n < free_space % num_gaps));
+        if self == Self::SpaceEvenly {
+            pos += gaps.next().unwrap_or(0);
+        }
+
+        let mut positions = Vec::with_capacity(sizes.len());
+        for size in sizes {
+            positions.push(pos);
+            pos = pos
+                .saturating_add(*size)
+                .saturating_add(gaps.next().unwrap_or(0));
+        }
+
+        positions
+    }
+}
+
+/// The alignment of the items along the cross axis of a list.
+///
+/// The alignment is only applied to items that are smaller than the list.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CrossAxisAlignment {
+    /// Places the items at the left (or top) of the list. This is the default.
+    #[default]
+    Start,
+
+    /// Centers the items.
+    Center,
+
+    /// Places the items at the right (or bottom) of the list.
+    End,
+}
+
+impl CrossAxisAlignment {
+    /// Returns the position of an item along the cross axis, given its size and
+    /// the total available size.
+    pub(crate) fn position(self, size: u16, available_size: u16) -> u16 {
+        let free_space = available_size.saturating_sub(size);
+        match self {
+            Self::Start => 0,
+            Self::Center => free_space / 2,
+            Self::End => free_space,
+        }
+    }
+}
+
 impl<T: Widget> StatefulWidget for ListView<'_, T> {
     type State = ListState;
 
-    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         state.set_num_elements(self.item_count);
         state.set_infinite_scrolling(self.infinite_scrolling);
 
@@ -183,86 +1044,978 @@ impl<T: Widget> StatefulWidget for ListView<'_, T> {
 
         // Set the base block
         self.block.render(area, buf);
+        let outer_area = area;
         let area = self.block.inner_if_some(area);
 
         // List is empty
         if self.item_count == 0 {
+            if let Some(placeholder) = self.empty_placeholder.take() {
+                placeholder(area, buf);
+            }
+            return;
+        }
+
+        if let Some(columns) = self.masonry {
+            self.render_masonry(area, buf, state, columns);
             return;
         }
 
+        // The overflow indicators are drawn on the border or in reserved cells.
+        let indicators_area = if self.block.is_some() {
+            outer_area
+        } else {
+            area
+        };
+        let area_with_indicators = area;
+        let area = self
+            .overflow_indicators
+            .as_ref()
+            .map_or(area, |indicators| {
+                indicators.list_area(area, self.scroll_axis)
+            });
+
+        // The scrollbar occupies the last column, or the last row in horizontal lists.
+        let scrollbar = self.scrollbar.take().map(|scrollbar| {
+            let (list_area, scrollbar_area) = match self.scroll_axis {
+                ScrollAxis::Vertical => (
+                    Rect {
+                        width: area.width.saturating_sub(1),
+                        ..area
+                    },
+                    Rect {
+                        x: area.right().saturating_sub(1),
+                        width: area.width.min(1),
+                        ..area
+                    },
+                ),
+                ScrollAxis::Horizontal => (
+                    Rect {
+                        height: area.height.saturating_sub(1),
+                        ..area
+                    },
+                    Rect {
+                        y: area.bottom().saturating_sub(1),
+                        height: area.height.min(1),
+                        ..area
+                    },
+                ),
+            };
+            (scrollbar, list_area, scrollbar_area)
+        });
+        let area = scrollbar
+            .as_ref()
+            .map_or(area, |(_, list_area, _)| *list_area);
+
+        // The gutter for the highlight symbol is reserved at the start of the
+        // cross axis.
+        let (gutter, area) = self.split_gutter(area, state);
+
         // Set the dimension along the scroll axis and the cross axis
         let (main_axis_size, cross_axis_size) = match self.scroll_axis {
             ScrollAxis::Vertical => (area.height, area.width),
             ScrollAxis::Horizontal => (area.width, area.height),
         };
 
-        // The coordinates of the first item with respect to the top left corner
-        let (mut scroll_axis_pos, cross_axis_pos) = match self.scroll_axis {
-            ScrollAxis::Vertical => (area.top(), area.left()),
-            ScrollAxis::Horizontal => (area.left(), area.top()),
-        };
+        // Items may be larger than the list along the cross axis, in which case
+        // the visible part is determined by the cross axis offset.
+        let (cross_axis_size, cross_axis_offset) = self.cross_axis_scroll(state, cross_axis_size);
+        state.cross_axis_offset = cross_axis_offset;
+
+        // A restored state may not fit the current items.
+        self.validate_restored(state, cross_axis_size);
+
+        // Jump to the requested position.
+        if let Some(fraction) = state.scroll_target.take() {
+            scroll_to_fraction(
+                state,
+                &self.builder,
+                self.item_count,
+                self.estimated_item_size,
+                fraction,
+                main_axis_size,
+                cross_axis_size,
+                self.scroll_axis,
+            );
+        }
 
-        // Determine which widgets to show on the viewport and how much space they
-        // get assigned to.
-        let mut viewport = layout_on_viewport(
+        // Move the selection away from items that can not be selected.
+        self.skip_unselectable(state, cross_axis_size);
+
+        // Pinned items occupy their slots at the edges of the viewport, the
+        // remaining items scroll between them.
+        let pinned_leading = self.pinned_leading.min(self.item_count);
+        let pinned_trailing = self.pinned_trailing.min(self.item_count - pinned_leading);
+        let scrolling_items = pinned_leading..self.item_count - pinned_trailing;
+
+        let mut available_size = main_axis_size;
+        let leading = layout_pinned(
             state,
             &self.builder,
-            self.item_count,
-            main_axis_size,
+            0..pinned_leading,
+            &mut available_size,
             cross_axis_size,
             self.scroll_axis,
-            self.scroll_padding,
         );
-
-        let (start, end) = (
-            state.view_state.offset,
-            viewport.len() + state.view_state.offset,
+        let trailing = layout_pinned(
+            state,
+            &self.builder,
+            scrolling_items.end..self.item_count,
+            &mut available_size,
+            cross_axis_size,
+            self.scroll_axis,
         );
-        for i in start..end {
+        let scrolling_size = available_size;
+
+        // Keep the selected item in place if the sizes of the items before it
+        // changed, e.g. because they finished loading.
+        if self.builder.has_placeholder() && !self.carousel && !self.snap {
+            restore_anchor(
+                state,
+                &self.builder,
+                scrolling_items.clone(),
+                cross_axis_size,
+                self.scroll_axis,
+            );
+        }
+
+        // Determine which widgets to show on the viewport and how much space they
+        // get assigned to.
+        let mut viewport = if scrolling_items.is_empty() || scrolling_size == 0 {
+            HashMap::new()
+        } else if self.carousel {
+            layout_on_viewport_centered(
+                state,
+                &self.builder,
+                scrolling_items.clone(),
+                scrolling_size,
+                cross_axis_size,
+                self.scroll_axis,
+            )
+        } else if self.snap {
+            layout_on_viewport_snapped(
+                state,
+                &self.builder,
+                scrolling_items.clone(),
+                scrolling_size,
+                cross_axis_size,
+                self.scroll_axis,
+                self.scroll_padding,
+            )
+        } else {
+            layout_on_viewport(
+                state,
+                &self.builder,
+                scrolling_items.clone(),
+                scrolling_size,
+                cross_axis_size,
+                self.scroll_axis,
+                self.scroll_padding,
+            )
+        };
+
+        let (start, end) = (
+            state.view_state.offset,
+            viewport.len() + state.view_state.offset,
+        );
+        let mut elements = Vec::with_capacity(viewport.len());
+        for i in start..end {
             let Some(element) = viewport.remove(&i) else {
                 break;
             };
             let visible_main_axis_size = element
                 .main_axis_size
                 .saturating_sub(element.truncation.value());
-            let area = match self.scroll_axis {
-                ScrollAxis::Vertical => Rect::new(
-                    cross_axis_pos,
-                    scroll_axis_pos,
-                    cross_axis_size,
-                    visible_main_axis_size,
-                ),
-                ScrollAxis::Horizontal => Rect::new(
-                    scroll_axis_pos,
-                    cross_axis_pos,
+            elements.push((element, visible_main_axis_size));
+        }
+        state.has_sticky_items |= elements.iter().any(|(element, _)| element.hints.sticky);
+        state.anchor = state.selected.and_then(|selected| {
+            let position = selected
+                .checked_sub(start)
+                .filter(|&i| i < elements.len())?;
+            let distance = elements[..position]
+                .iter()
+                .fold(0u16, |sum, (_, size)| sum.saturating_add(*size));
+            Some((selected, distance))
+        });
+
+        // The number of items that are hidden before and after the viewport.
+        let hidden_before = start.saturating_sub(scrolling_items.start);
+        let hidden_after = scrolling_items
+            .end
+            .saturating_sub(start + elements.len())
+            .min(scrolling_items.len());
+        let counts = if self.reverse {
+            (hidden_after, hidden_before)
+        } else {
+            (hidden_before, hidden_after)
+        };
+
+        // Refine the estimated sizes with the sizes of the items that were laid out.
+        if self.estimated_item_size.is_some() && state.size_index.len() == self.item_count {
+            for (i, (element, _)) in elements.iter().enumerate() {
+                state.size_index.set(start + i, element.main_axis_size);
+            }
+        }
+
+        // The positions of the items along the main axis, measured from the
+        // start of the viewport (the bottom/right edge in reverse mode).
+        let sizes: Vec<u16> = elements.iter().map(|(_, size)| *size).collect();
+        let alignment = if elements.len() == scrolling_items.len() && !self.carousel {
+            self.alignment
+        } else {
+            MainAxisAlignment::Start
+        };
+        let leading_size = leading.iter().map(|(_, size)| *size).sum::<u16>();
+        let mut positions: Vec<u16> = alignment
+            .positions(&sizes, scrolling_size)
+            .into_iter()
+            .map(|pos| pos + leading_size)
+            .collect();
+
+        // In carousel mode the selected item is centered, which may leave blank
+        // space before the first item.
+        if self.carousel && !elements.is_empty() {
+            let selected = state
+                .selected
+                .unwrap_or(0)
+                .clamp(scrolling_items.start, scrolling_items.end - 1)
+                .saturating_sub(start);
+            let selected_size = sizes.get(selected).copied().unwrap_or(0);
+            let size_before: u16 = sizes.iter().take(selected).sum();
+            let leading_space =
+                (scrolling_size.saturating_sub(selected_size) / 2).saturating_sub(size_before);
+            for pos in &mut positions {
+                *pos += leading_space;
+            }
+        }
+
+        // Leading pinned items are placed at the start and trailing pinned items
+        // at the end of the viewport.
+        let trailing_size = trailing.iter().map(|(_, size)| *size).sum::<u16>();
+        let leading_positions = MainAxisAlignment::Start.positions(
+            &leading.iter().map(|(_, size)| *size).collect::<Vec<_>>(),
+            leading_size,
+        );
+        let trailing_positions = MainAxisAlignment::Start
+            .positions(
+                &trailing.iter().map(|(_, size)| *size).collect::<Vec<_>>(),
+                trailing_size,
+            )
+            .into_iter()
+            .map(|pos| pos + main_axis_size - trailing_size);
+
+        // A sticky item that was scrolled past is rendered on top of the items
+        // at the start of the scrolling region.
+        let trailing_start = scrolling_items.end;
+        let sticky = if self.carousel || elements.is_empty() {
+            None
+        } else {
+            self.sticky_element(
+                state,
+                &elements,
+                &positions,
+                scrolling_items,
+                leading_size,
+                scrolling_size,
+                cross_axis_size,
+            )
+        };
+
+        let elements = (0..)
+            .zip(leading.into_iter().zip(leading_positions))
+            .chain((start..).zip(elements.into_iter().zip(positions)))
+            .map(|element| (element, false))
+            .chain(sticky.map(|element| (element, true)))
+            .chain(
+                (trailing_start..)
+                    .zip(trailing.into_iter().zip(trailing_positions))
+                    .map(|element| (element, false)),
+            );
+
+        let mut selected_area = None;
+        for ((index, ((element, visible_main_axis_size), main_axis_pos)), is_sticky) in elements {
+            // The sticky item covers the item that was rendered in its place.
+            if is_sticky {
+                let sticky_area = self.item_area(
+                    area,
+                    main_axis_pos,
                     visible_main_axis_size,
-                    cross_axis_size,
-                ),
+                    &LayoutHints::default(),
+                );
+                Clear.render(sticky_area, buf);
+                buf.set_style(sticky_area, self.style);
+            }
+            let is_selectable = element.hints.selectable;
+            let item_area = self.render_element(
+                element,
+                main_axis_pos,
+                visible_main_axis_size,
+                area,
+                cross_axis_offset,
+                buf,
+                &mut state.scratch.0,
+            );
+            if state.selected == Some(index) {
+                selected_area = Some(item_area);
+            }
+            let status = ItemStatus {
+                index,
+                is_selected: state.selected == Some(index),
+                is_hovered: state.hovered == Some(index),
+                is_selectable,
+            };
+            self.styles.patch(buf, item_area, status);
+        }
+
+        // The selected item is highlighted on top of the rendered widget.
+        if let Some(selected_area) = selected_area {
+            self.render_highlight(selected_area, gutter, buf);
+        }
+
+        if let Some(indicators) = &self.overflow_indicators {
+            let (indicators_area, on_border) = match indicators.placement {
+                OverflowPlacement::Border => (indicators_area, self.block.is_some()),
+                OverflowPlacement::Inside => (area_with_indicators, false),
+            };
+            indicators.render(indicators_area, buf, self.scroll_axis, counts, on_border);
+        }
+
+        // The scrollbar area is empty if the list has no width, e.g. while the
+        // terminal is resized.
+        if let Some((scrollbar, _, scrollbar_area)) =
+            scrollbar.filter(|(_, _, area)| !area.is_empty())
+        {
+            let (position, total) = scroll_position(
+                state,
+                &self.builder,
+                self.item_count,
+                self.estimated_item_size,
+                cross_axis_size,
+                self.scroll_axis,
+            );
+            let viewport = u64::from(main_axis_size);
+            // In reverse mode the start of the list is at the end of the scrollbar.
+            let position = if self.reverse {
+                total.saturating_sub(viewport).saturating_sub(position)
+            } else {
+                position
+            };
+            let mut scrollbar_state =
+                ScrollbarState::new(to_usize(total.saturating_sub(viewport) + 1))
+                    .position(to_usize(position))
+                    .viewport_content_length(usize::from(main_axis_size));
+            scrollbar.render(scrollbar_area, buf, &mut scrollbar_state);
+        }
+    }
+}
+
+impl<T: Widget> ListView<'_, T> {
+    /// Clamps the selection and the offset of a restored state to the items. The
+    /// truncation of the first item is dropped if the item became smaller.
+    fn validate_restored(&self, state: &mut ListState, cross_axis_size: u16) {
+        if !std::mem::take(&mut state.restored) {
+            return;
+        }
+        let last = self.item_count.saturating_sub(1);
+        state.selected = state.selected.map(|selected| selected.min(last));
+        if state.view_state.offset > last {
+            state.view_state = ViewState::default();
+            return;
+        }
+
+        let offset = state.view_state.offset;
+        let size = self.builder.main_axis_size(&ListBuildContext {
+            index: offset,
+            is_selected: state.selected == Some(offset),
+            scroll_axis: self.scroll_axis,
+            cross_axis_size,
+        });
+        if state.view_state.first_truncated >= size {
+            state.view_state.first_truncated = 0;
+        }
+    }
+
+    /// Renders the items in masonry mode. The viewport is scrolled such that the
+    /// selected item is visible.
+    fn render_masonry(&self, area: Rect, buf: &mut Buffer, state: &mut ListState, columns: usize) {
+        let columns = columns.min(usize::from(area.width)).max(1);
+        #[allow(clippy::cast_possible_truncation)]
+        let column_width = area.width / columns as u16;
+        let measure = |index| {
+            self.builder.main_axis_size(&ListBuildContext {
+                index,
+                is_selected: false,
+                scroll_axis: ScrollAxis::Vertical,
+                cross_axis_size: column_width,
+            })
+        };
+
+        let layout = &mut state.masonry;
+        layout.prepare(columns, column_width, self.item_count);
+
+        // Bring the selected item into view.
+        let height = u64::from(area.height);
+        if let Some(selected) = state.selected.map(|index| index.min(self.item_count - 1)) {
+            while layout.placements.len() <= selected {
+                layout.place(measure(layout.placements.len()));
+            }
+            let placement = layout.placements[selected];
+            if placement.start < layout.scroll || u64::from(placement.size) > height {
+                layout.scroll = placement.start;
+            } else if placement.end() > layout.scroll + height {
+                layout.scroll = placement.end() - height;
+            }
+        }
+
+        // Place the items up to the end of the viewport, and one more item in
+        // each column to navigate into.
+        let end = layout.scroll + height;
+        while layout.placements.len() < self.item_count && !layout.is_filled_to(end) {
+            layout.place(measure(layout.placements.len()));
+        }
+
+        let scroll = layout.scroll;
+        let visible: Vec<(usize, Placement)> = layout
+            .placements
+            .iter()
+            .copied()
+            .enumerate()
+            .take_while(|(_, placement)| placement.start < end)
+            .filter(|(_, placement)| placement.end() > scroll)
+            .collect();
+
+        for (index, placement) in visible {
+            let context = ListBuildContext {
+                index,
+                is_selected: state.selected == Some(index),
+                scroll_axis: ScrollAxis::Vertical,
+                cross_axis_size: column_width,
+            };
+            let mut item = self.builder.call_closure(&context);
+            let block = item.block.take();
+
+            let truncated_top = scroll.saturating_sub(placement.start);
+            let truncated_bot = placement.end().saturating_sub(end);
+            let truncation = match (truncated_top, truncated_bot) {
+                (0, 0) => Truncation::None,
+                (0, value) => Truncation::Bot(u16::try_from(value).unwrap_or(u16::MAX)),
+                (value, _) => Truncation::Top(u16::try_from(value).unwrap_or(u16::MAX)),
+            };
+            let visible_size = u64::from(placement.size) - truncated_top - truncated_bot;
+
+            #[allow(clippy::cast_possible_truncation)]
+            let item_area = Rect {
+                x: area.x + placement.column as u16 * column_width,
+                y: area.y + u16::try_from(placement.start.saturating_sub(scroll)).unwrap_or(0),
+                width: column_width,
+                height: u16::try_from(visible_size).unwrap_or(0),
+            };
+            let widget = WithMargin {
+                widget: item.widget,
+                margin_before: item.hints.margin_leading,
+                margin_after: item.hints.margin_trailing,
+                scroll_axis: ScrollAxis::Vertical,
             };
+            let status = ItemStatus {
+                index,
+                is_selected: state.selected == Some(index),
+                is_hovered: state.hovered == Some(index),
+                is_selectable: item.hints.selectable,
+            };
+            if truncation == Truncation::None {
+                widget
+                    .map(|widget| WithBlock { widget, block })
+                    .render(item_area, buf);
+            } else if let (Some(render), None) = (self.builder.render_clipped, &block) {
+                render_clipped(
+                    render,
+                    widget,
+                    item_area,
+                    buf,
+                    placement.size,
+                    &truncation,
+                    0,
+                    area,
+                );
+            } else {
+                render_truncated(
+                    widget.map(|widget| WithBlock { widget, block }),
+                    item_area,
+                    buf,
+                    placement.size,
+                    &truncation,
+                    self.style,
+                    ScrollAxis::Vertical,
+                    0,
+                    area,
+                    &mut state.scratch.0,
+                );
+            }
+            self.render_truncation_marker(item_area, &truncation, ScrollAxis::Vertical, buf);
+            self.styles.patch(buf, item_area, status);
+            if state.selected == Some(index) {
+                buf.set_style(item_area, self.highlight_style);
+            }
+        }
+    }
+
+    /// Renders an item at the given position along the main axis.
+    #[allow(clippy::too_many_arguments)]
+    fn render_element(
+        &self,
+        element: ViewportElement<T>,
+        main_axis_pos: u16,
+        visible_main_axis_size: u16,
+        area: Rect,
+        cross_axis_offset: u16,
+        buf: &mut Buffer,
+        scratch: &mut Buffer,
+    ) -> Rect {
+        // The item is laid out on the full cross axis size and shifted by the
+        // cross axis offset afterwards.
+        let clip = area;
+        let area = match (self.scroll_axis, self.virtual_cross_axis_size) {
+            (ScrollAxis::Vertical, Some(size)) => Rect {
+                width: size.max(area.width),
+                ..area
+            },
+            (ScrollAxis::Horizontal, Some(size)) => Rect {
+                height: size.max(area.height),
+                ..area
+            },
+            (_, None) => area,
+        };
+        let area = self.item_area(area, main_axis_pos, visible_main_axis_size, &element.hints);
+        let block = element.block;
+
+        // In reverse mode the start of the list is at the bottom, so
+        // the truncated side and the margins of each item are mirrored.
+        let (truncation, margin_before, margin_after) = if self.reverse {
+            (
+                element.truncation.mirrored(),
+                element.hints.margin_trailing,
+                element.hints.margin_leading,
+            )
+        } else {
+            (
+                element.truncation,
+                element.hints.margin_leading,
+                element.hints.margin_trailing,
+            )
+        };
+        let widget = WithMargin {
+            widget: element.widget,
+            margin_before,
+            margin_after,
+            scroll_axis: self.scroll_axis,
+        };
 
-            // Render truncated widgets.
-            if element.truncation.value() > 0 {
+        // Render truncated widgets. Items in a block are always rendered into
+        // the hidden buffer.
+        if truncation.value() > 0 || self.virtual_cross_axis_size.is_some() {
+            if let (Some(render), None) = (self.builder.render_clipped, &block) {
+                render_clipped(
+                    render,
+                    widget,
+                    area,
+                    buf,
+                    element.main_axis_size,
+                    &truncation,
+                    cross_axis_offset,
+                    clip,
+                );
+            } else {
                 render_truncated(
-                    element.widget,
+                    widget.map(|widget| WithBlock { widget, block }),
                     area,
                     buf,
                     element.main_axis_size,
-                    &element.truncation,
+                    &truncation,
                     self.style,
                     self.scroll_axis,
+                    cross_axis_offset,
+                    clip,
+                    scratch,
                 );
-            } else {
-                element.widget.render(area, buf);
             }
+        } else {
+            widget
+                .map(|widget| WithBlock { widget, block })
+                .render(area, buf);
+        }
+
+        // The visible area of the item on the screen.
+        let visible = match self.scroll_axis {
+            ScrollAxis::Vertical => {
+                let visible = area.intersection(Rect {
+                    x: clip.x + cross_axis_offset,
+                    ..clip
+                });
+                Rect {
+                    x: visible.x - cross_axis_offset,
+                    ..visible
+                }
+            }
+            ScrollAxis::Horizontal => {
+                let visible = area.intersection(Rect {
+                    y: clip.y + cross_axis_offset,
+                    ..clip
+                });
+                Rect {
+                    y: visible.y - cross_axis_offset,
+                    ..visible
+                }
+            }
+        };
+        self.render_truncation_marker(visible, &truncation, self.scroll_axis, buf);
+        visible
+    }
+
+    /// Marks the cut edge of a truncated item.
+    fn render_truncation_marker(
+        &self,
+        area: Rect,
+        truncation: &Truncation,
+        scroll_axis: ScrollAxis,
+        buf: &mut Buffer,
+    ) {
+        let Some(marker) = &self.truncation_marker else {
+            return;
+        };
+        match truncation {
+            Truncation::Top(value) if *value > 0 => {
+                marker.render(area, buf, scroll_axis, false);
+            }
+            Truncation::Bot(value) if *value > 0 => {
+                marker.render(area, buf, scroll_axis, true);
+            }
+            _ => {}
+        }
+    }
+
+    /// Draws the highlight symbol in the gutter next to the selected item and
+    /// patches the item and the gutter with the highlight style.
+    fn render_highlight(&self, selected_area: Rect, gutter: Rect, buf: &mut Buffer) {
+        let gutter = match self.scroll_axis {
+            ScrollAxis::Vertical => Rect {
+                y: selected_area.y,
+                height: selected_area.height,
+                ..gutter
+            },
+            ScrollAxis::Horizontal => Rect {
+                x: selected_area.x,
+                width: selected_area.width,
+                ..gutter
+            },
+        };
+        buf.set_style(selected_area, self.highlight_style);
+        if gutter.is_empty() {
+            return;
+        }
+        buf.set_style(gutter, self.highlight_style);
+        if let Some(symbol) = self.highlight_symbol {
+            buf.set_stringn(
+                gutter.x,
+                gutter.y,
+                symbol,
+                usize::from(gutter.width),
+                self.highlight_style,
+            );
+        }
+    }
+}
+
+/// An item together with its visible size and its position along the main axis.
+type PositionedElement<T> = ((ViewportElement<T>, u16), u16);
+
+impl<T> ListView<'_, T> {
+    /// Splits the area into the gutter for the highlight symbol and the area
+    /// of the items. The gutter is on the left of vertical lists and on top of
+    /// horizontal lists.
+    fn split_gutter(&self, area: Rect, state: &ListState) -> (Rect, Rect) {
+        let reserve = match self.highlight_spacing {
+            HighlightSpacing::Always => true,
+            HighlightSpacing::WhenSelected => state.selected.is_some(),
+            HighlightSpacing::Never => false,
+        };
+        let size = match (self.highlight_symbol, reserve) {
+            (Some(symbol), true) => match self.scroll_axis {
+                ScrollAxis::Vertical => u16::try_from(Span::raw(symbol).width()).unwrap_or(0),
+                ScrollAxis::Horizontal => 1,
+            },
+            _ => 0,
+        };
+
+        match self.scroll_axis {
+            ScrollAxis::Vertical => {
+                let size = size.min(area.width);
+                (
+                    Rect {
+                        width: size,
+                        ..area
+                    },
+                    Rect {
+                        x: area.x + size,
+                        width: area.width - size,
+                        ..area
+                    },
+                )
+            }
+            ScrollAxis::Horizontal => {
+                let size = size.min(area.height);
+                (
+                    Rect {
+                        height: size,
+                        ..area
+                    },
+                    Rect {
+                        y: area.y + size,
+                        height: area.height - size,
+                        ..area
+                    },
+                )
+            }
+        }
+    }
+
+    /// Returns the size of the items along the cross axis, given the size of
+    /// the list, and the cross axis offset clamped such that the end of the
+    /// items is not scrolled past.
+    fn cross_axis_scroll(&self, state: &ListState, cross_axis_size: u16) -> (u16, u16) {
+        let Some(size) = self.virtual_cross_axis_size else {
+            return (cross_axis_size, 0);
+        };
+        let size = size.max(cross_axis_size);
+        let offset = state.cross_axis_offset.min(size - cross_axis_size);
+        (size, offset)
+    }
+
+    /// Determines the sticky item that should be shown at the start of the
+    /// scrolling region, if any. The sticky item is pushed out by the next
+    /// sticky item on the viewport.
+    ///
+    /// Returns the index of the sticky item, the item, its visible size and its
+    /// position.
+    #[allow(clippy::too_many_arguments)]
+    fn sticky_element(
+        &self,
+        state: &mut ListState,
+        elements: &[(ViewportElement<T>, u16)],
+        positions: &[u16],
+        scrolling_items: Range<usize>,
+        region_pos: u16,
+        region_size: u16,
+        cross_axis_size: u16,
+    ) -> Option<(usize, PositionedElement<T>)> {
+        let offset = state.view_state.offset;
+        let (first, _) = elements.first()?;
+        if !state.has_sticky_items || first.hints.sticky && first.truncation == Truncation::None {
+            return None;
+        }
+
+        let (index, item) = find_sticky(
+            state,
+            &self.builder,
+            scrolling_items,
+            offset,
+            cross_axis_size,
+            self.scroll_axis,
+        )?;
+        let size = item.main_axis_size;
+
+        // The next sticky item pushes the current one out of the viewport.
+        let pushed_by = elements
+            .iter()
+            .zip(positions)
+            .enumerate()
+            .find(|(i, ((element, _), _))| offset + i > index && element.hints.sticky)
+            .map_or(0, |(_, (_, pos))| {
+                size.saturating_sub(pos.saturating_sub(region_pos))
+            });
+        if pushed_by >= size {
+            return None;
+        }
+
+        let truncation = if pushed_by > 0 {
+            Truncation::Top(pushed_by)
+        } else if size > region_size {
+            Truncation::Bot(size - region_size)
+        } else {
+            Truncation::None
+        };
+        let visible_size = (size - pushed_by).min(region_size);
+
+        Some((
+            index,
+            (
+                (ViewportElement::from_item(item, truncation), visible_size),
+                region_pos,
+            ),
+        ))
+    }
+
+    /// If the selected item can not be selected, the selection moves on to the
+    /// next selectable item in the direction of the last selection change. If
+    /// there is none, it moves in the opposite direction instead. The selection
+    /// is only checked if it changed since the last render.
+    fn skip_unselectable(&self, state: &mut ListState, cross_axis_size: u16) {
+        let Some(selected) = state.selected else {
+            return;
+        };
+        if state.checked_selection == Some(selected) {
+            return;
+        }
+        let is_selectable = |index: &usize| {
+            let context = ListBuildContext {
+                index: *index,
+                is_selected: true,
+                scroll_axis: self.scroll_axis,
+                cross_axis_size,
+            };
+            self.builder.call_closure(&context).hints.selectable
+        };
+        if selected >= self.item_count || is_selectable(&selected) {
+            state.checked_selection = Some(selected);
+            return;
+        }
+
+        let (before, after) = (0..selected, selected + 1..self.item_count);
+        let selectable = match (state.direction, self.infinite_scrolling) {
+            (Direction::Forward, true) => after.chain(before).find(is_selectable),
+            (Direction::Forward, false) => after.chain(before.rev()).find(is_selectable),
+            (Direction::Backward, true) => before.rev().chain(after.rev()).find(is_selectable),
+            (Direction::Backward, false) => before.rev().chain(after).find(is_selectable),
+        };
+        state.select(selectable);
+        state.checked_selection = selectable;
+    }
+
+    /// Returns the area of an item on the screen, given its position and size
+    /// along the main axis relative to the start of the viewport, and its size
+    /// along the cross axis. Items without a cross axis size span the full list.
+    fn item_area(
+        &self,
+        area: Rect,
+        main_axis_pos: u16,
+        main_axis_size: u16,
+        hints: &LayoutHints,
+    ) -> Rect {
+        let (main_axis_start, main_axis_end, cross_axis_start, total_cross_axis_size) =
+            match self.scroll_axis {
+                ScrollAxis::Vertical => (area.top(), area.bottom(), area.left(), area.width),
+                ScrollAxis::Horizontal => (area.left(), area.right(), area.top(), area.height),
+            };
+        let pos = if self.reverse {
+            main_axis_end
+                .saturating_sub(main_axis_pos)
+                .saturating_sub(main_axis_size)
+                .max(main_axis_start)
+        } else {
+            main_axis_start + main_axis_pos
+        };
+
+        let cross_axis_size = hints.cross_axis_size.map_or(total_cross_axis_size, |size| {
+            size.min(total_cross_axis_size)
+        });
+        let cross_axis_alignment = hints
+            .cross_axis_alignment
+            .unwrap_or(self.cross_axis_alignment);
+        let cross_axis_pos = cross_axis_start
+            + cross_axis_alignment.position(cross_axis_size, total_cross_axis_size);
+
+        match self.scroll_axis {
+            ScrollAxis::Vertical => Rect::new(cross_axis_pos, pos, cross_axis_size, main_axis_size),
+            ScrollAxis::Horizontal => {
+                Rect::new(pos, cross_axis_pos, main_axis_size, cross_axis_size)
+            }
+        }
+    }
+}
+
+/// Renders a widget with space before and after it along the main axis.
+struct WithMargin<T> {
+    widget: T,
+    margin_before: u16,
+    margin_after: u16,
+    scroll_axis: ScrollAxis,
+}
 
-            scroll_axis_pos += visible_main_axis_size;
+impl<T> WithMargin<T> {
+    /// Replaces the widget, keeping the margins.
+    fn map<U>(self, f: impl FnOnce(T) -> U) -> WithMargin<U> {
+        WithMargin {
+            widget: f(self.widget),
+            margin_before: self.margin_before,
+            margin_after: self.margin_after,
+            scroll_axis: self.scroll_axis,
         }
     }
 }
 
+/// Renders a widget inside an optional block.
+struct WithBlock<T> {
+    widget: T,
+    block: Option<Block<'static>>,
+}
+
+impl<T: Widget> Widget for WithBlock<T> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner = self.block.inner_if_some(area);
+        self.block.render(area, buf);
+        self.widget.render(inner, buf);
+    }
+}
+
+impl<T: Widget> Widget for WithMargin<T> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let margin = self.margin_before.saturating_add(self.margin_after);
+        let area = match self.scroll_axis {
+            ScrollAxis::Vertical => Rect {
+                y: area.y.saturating_add(self.margin_before),
+                height: area.height.saturating_sub(margin),
+                ..area
+            },
+            ScrollAxis::Horizontal => Rect {
+                x: area.x.saturating_add(self.margin_before),
+                width: area.width.saturating_sub(margin),
+                ..area
+            },
+        };
+        self.widget.render(area, buf);
+    }
+}
+
+/// Returns the thickness of `block` along the main and the cross axis, i.e. the
+/// space taken up by its borders, titles and padding.
+fn block_thickness(block: Option<&Block>, scroll_axis: ScrollAxis) -> (u16, u16) {
+    // A block only reports its inner area for a given area. The size of the
+    // measured area is arbitrary, it only has to be large enough that the
+    // borders and the padding of the block fit into it.
+    const MEASURED_AREA: Rect = Rect {
+        x: 0,
+        y: 0,
+        width: u16::MAX,
+        height: u16::MAX,
+    };
+    let Some(block) = block else {
+        return (0, 0);
+    };
+    let inner = block.inner(MEASURED_AREA);
+    let (vertical, horizontal) = (
+        MEASURED_AREA.height - inner.height,
+        MEASURED_AREA.width - inner.width,
+    );
+    match scroll_axis {
+        ScrollAxis::Vertical => (vertical, horizontal),
+        ScrollAxis::Horizontal => (horizontal, vertical),
+    }
+}
+
 /// Render a truncated widget into a buffer. The method renders the widget fully into
-/// a hidden buffer and moves the visible content into `buf`.
-fn render_truncated<T: Widget>(
+/// the `scratch` buffer and moves the visible content into `buf`. The scratch
+/// buffer is reused, so that no allocation is needed once it is large enough.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn render_truncated<T: Widget>(
     item: T,
     available_area: Rect,
     buf: &mut Buffer,
@@ -270,56 +2023,149 @@ fn render_truncated<T: Widget>(
     truncation: &Truncation,
     base_style: Style,
     scroll_axis: ScrollAxis,
+    cross_axis_offset: u16,
+    clip: Rect,
+    scratch: &mut Buffer,
 ) {
-    // Create an hidden buffer for rendering the truncated element
     let (width, height) = match scroll_axis {
         ScrollAxis::Vertical => (available_area.width, untruncated_size),
         ScrollAxis::Horizontal => (untruncated_size, available_area.height),
     };
-    let mut hidden_buffer = Buffer::empty(Rect {
+    scratch.resize(Rect {
         x: available_area.left(),
         y: available_area.top(),
         width,
         height,
     });
-    hidden_buffer.set_style(hidden_buffer.area, base_style);
-    item.render(hidden_buffer.area, &mut hidden_buffer);
+    scratch.reset();
+    scratch.set_style(scratch.area, base_style);
+    item.render(scratch.area, scratch);
+
+    // Copy the visible part from the scratch buffer to the main buffer, one
+    // row at a time. Along the main axis the truncated part is skipped, along
+    // the cross axis the item is shifted by the cross axis offset.
+    let offset = match truncation {
+        Truncation::Top(value) => *value,
+        _ => 0,
+    };
+    let (skip_x, skip_y, shift_x, shift_y) = match scroll_axis {
+        ScrollAxis::Vertical => (0, offset, cross_axis_offset, 0),
+        ScrollAxis::Horizontal => (offset, 0, 0, cross_axis_offset),
+    };
+    let clip = clip.intersection(buf.area);
+    let left = available_area
+        .left()
+        .saturating_sub(shift_x)
+        .max(clip.left());
+    let right = available_area
+        .right()
+        .saturating_sub(shift_x)
+        .min(clip.right());
+    let top = available_area.top().saturating_sub(shift_y).max(clip.top());
+    let bottom = available_area
+        .bottom()
+        .saturating_sub(shift_y)
+        .min(clip.bottom());
+    if left >= right {
+        return;
+    }
+    let len = usize::from(right - left);
+    for y in top..bottom {
+        let to = buf.index_of(left, y);
+        let from = scratch.index_of(left + shift_x + skip_x, y + shift_y + skip_y);
+        buf.content[to..to + len].clone_from_slice(&scratch.content[from..from + len]);
+    }
+}
 
-    // Copy the visible part from the hidden buffer to the main buffer
-    match scroll_axis {
-        ScrollAxis::Vertical => {
-            let offset = match truncation {
-                Truncation::Top(value) => *value,
-                _ => 0,
-            };
-            for y in available_area.top()..available_area.bottom() {
-                let y_off = y + offset;
-                for x in available_area.left()..available_area.right() {
-                    if let Some(to) = buf.cell_mut(Position::new(x, y)) {
-                        if let Some(from) = hidden_buffer.cell(Position::new(x, y_off)) {
-                            *to = from.clone();
-                        }
-                    }
-                }
-            }
-        }
-        ScrollAxis::Horizontal => {
-            let offset = match truncation {
-                Truncation::Top(value) => *value,
-                _ => 0,
-            };
-            for x in available_area.left()..available_area.right() {
-                let x_off = x + offset;
-                for y in available_area.top()..available_area.bottom() {
-                    if let Some(to) = buf.cell_mut(Position::new(x, y)) {
-                        if let Some(from) = hidden_buffer.cell(Position::new(x_off, y)) {
-                            *to = from.clone();
-                        }
-                    }
-                }
-            }
-        }
+/// Renders the visible part of a truncated widget with its `render_clipped`
+/// function. The margins of the widget are not passed to `render`, they are
+/// removed from the clip.
+#[allow(clippy::too_many_arguments)]
+fn render_clipped<T>(
+    render: ClipRenderer<T>,
+    item: WithMargin<T>,
+    available_area: Rect,
+    buf: &mut Buffer,
+    untruncated_size: u16,
+    truncation: &Truncation,
+    cross_axis_offset: u16,
+    clip: Rect,
+) {
+    let clip = clip.intersection(buf.area);
+    let skip = match truncation {
+        Truncation::Top(value) => i32::from(*value),
+        _ => 0,
+    };
+    // The positions along the main and the cross axis, as (start, end).
+    let (main, cross, clip_main, clip_cross) = match item.scroll_axis {
+        ScrollAxis::Vertical => (
+            (available_area.top(), available_area.bottom()),
+            (available_area.left(), available_area.right()),
+            (clip.top(), clip.bottom()),
+            (clip.left(), clip.right()),
+        ),
+        ScrollAxis::Horizontal => (
+            (available_area.left(), available_area.right()),
+            (available_area.top(), available_area.bottom()),
+            (clip.left(), clip.right()),
+            (clip.top(), clip.bottom()),
+        ),
     };
+    let (main_start, cross_start) = (
+        i32::from(main.0),
+        i32::from(cross.0) - i32::from(cross_axis_offset),
+    );
+
+    // The visible range of the widget, relative to the start of the item.
+    let main_range = (
+        (i32::from(clip_main.0) - main_start).max(0) + skip,
+        (i32::from(clip_main.1.min(main.1)) - main_start) + skip,
+    );
+    let main_range = (
+        main_range.0.max(i32::from(item.margin_before)),
+        main_range
+            .1
+            .min(i32::from(untruncated_size) - i32::from(item.margin_after)),
+    );
+    let cross_range = (
+        (i32::from(clip_cross.0) - cross_start).max(0),
+        (i32::from(clip_cross.1) - cross_start).min(i32::from(cross.1 - cross.0)),
+    );
+    if main_range.0 >= main_range.1 || cross_range.0 >= cross_range.1 {
+        return;
+    }
+
+    let to_u16 = |value: i32| u16::try_from(value).unwrap_or_default();
+    let full_main = untruncated_size.saturating_sub(item.margin_before + item.margin_after);
+    let (main_pos, main_size) = (
+        to_u16(main_start + main_range.0 - skip),
+        to_u16(main_range.1 - main_range.0),
+    );
+    let (cross_pos, cross_size) = (
+        to_u16(cross_start + cross_range.0),
+        to_u16(cross_range.1 - cross_range.0),
+    );
+    let (main_offset, cross_offset) = (
+        to_u16(main_range.0 - i32::from(item.margin_before)),
+        to_u16(cross_range.0),
+    );
+    let (area, clip) = match item.scroll_axis {
+        ScrollAxis::Vertical => (
+            Rect::new(cross_pos, main_pos, cross_size, main_size),
+            Clip {
+                full_size: Size::new(cross.1 - cross.0, full_main),
+                offset: Position::new(cross_offset, main_offset),
+            },
+        ),
+        ScrollAxis::Horizontal => (
+            Rect::new(main_pos, cross_pos, main_size, cross_size),
+            Clip {
+                full_size: Size::new(full_main, cross.1 - cross.0),
+                offset: Position::new(main_offset, cross_offset),
+            },
+        ),
+    };
+    render(item.widget, area, clip, buf);
 }
 
 #[derive(Debug, Clone, Default, PartialEq, PartialOrd, Eq, Ord)]
@@ -337,6 +2183,15 @@ impl Truncation {
             Self::None => 0,
         }
     }
+
+    /// Swaps the truncated side. Used for lists that are laid out in reverse.
+    pub(crate) fn mirrored(self) -> Self {
+        match self {
+            Self::Top(value) => Self::Bot(value),
+            Self::Bot(value) => Self::Top(value),
+            Self::None => Self::None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -345,7 +2200,12 @@ mod test {
     use ratatui::widgets::Block;
 
     use super::*;
-    use ratatui::widgets::Borders;
+    use ratatui::{
+        style::{Color, Modifier},
+        text::{Line, Text},
+        widgets::{Borders, ScrollbarOrientation},
+    };
+    use std::{cell::Cell, rc::Rc};
 
     struct TestItem {}
     impl Widget for TestItem {
@@ -504,6 +2364,1075 @@ mod test {
         )
     }
 
+    #[test]
+    fn reverse_anchored_at_bottom() {
+        // given
+        let (area, mut buf, list, mut state) = test_data(11);
+        let list = list.reverse(true);
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "     ",
+                "     ",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+            ]),
+        )
+    }
+
+    #[test]
+    fn reverse_truncated() {
+        // given
+        let (area, mut buf, list, mut state) = test_data(8);
+        let list = list.reverse(true);
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+            ]),
+        );
+
+        // when
+        let (_, mut buf, list, _) = test_data(8);
+        state.select(Some(2));
+        list.reverse(true).render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+            ]),
+        )
+    }
+
+    #[test]
+    fn align_center() {
+        // given
+        let (area, mut buf, list, mut state) = test_data(11);
+        let list = list.align(MainAxisAlignment::Center);
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "     ",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "     ",
+            ]),
+        )
+    }
+
+    #[test]
+    fn alignment_positions() {
+        let sizes = [2, 2, 2];
+
+        assert_eq!(
+            MainAxisAlignment::Start.positions(&sizes, 11),
+            vec![0, 2, 4]
+        );
+        assert_eq!(
+            MainAxisAlignment::Center.positions(&sizes, 11),
+            vec![2, 4, 6]
+        );
+        assert_eq!(MainAxisAlignment::End.positions(&sizes, 11), vec![5, 7, 9]);
+        assert_eq!(
+            MainAxisAlignment::SpaceBetween.positions(&sizes, 11),
+            vec![0, 5, 9]
+        );
+        assert_eq!(
+            MainAxisAlignment::SpaceEvenly.positions(&sizes, 11),
+            vec![2, 5, 8]
+        );
+
+        // Content larger than the viewport is packed at the start
+        assert_eq!(MainAxisAlignment::End.positions(&sizes, 4), vec![0, 2, 4]);
+    }
+
+    #[test]
+    fn cross_axis_size_and_alignment() {
+        // given
+        let area = Rect::new(0, 0, 5, 8);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder = ListBuilder::with_cross_axis_size(|_| (TestItem {}, 3, 3));
+        let list = ListView::new(builder, 3).cross_axis_alignment(CrossAxisAlignment::Center);
+        state.select(Some(2));
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                " │ │ ",
+                " └─┘ ",
+                " ┌─┐ ",
+                " │ │ ",
+                " └─┘ ",
+                " ┌─┐ ",
+                " │ │ ",
+                " └─┘ ",
+            ]),
+        )
+    }
+
+    #[test]
+    fn carousel_first_item_centered() {
+        // given
+        let (area, mut buf, list, mut state) = test_data(9);
+        let list = list.carousel(true);
+        state.select(Some(0));
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "     ",
+                "     ",
+                "     ",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+            ]),
+        )
+    }
+
+    #[test]
+    fn carousel_horizontal() {
+        // given
+        let area = Rect::new(0, 0, 9, 2);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder = ListBuilder::new(|_| (TestItem {}, 3));
+        let list = ListView::new(builder, 3)
+            .scroll_axis(ScrollAxis::Horizontal)
+            .carousel(true);
+        state.select(Some(2));
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["┌─┐┌─┐   ", "└─┘└─┘   "]))
+    }
+
+    #[test]
+    fn pinned_items() {
+        // given
+        let area = Rect::new(0, 0, 1, 4);
+        let mut state = ListState::default();
+        let list = || {
+            let builder = ListBuilder::new(|context| (Line::from(context.index.to_string()), 1));
+            ListView::new(builder, 6)
+                .pinned_leading(1)
+                .pinned_trailing(1)
+        };
+
+        // when
+        let mut buf = Buffer::empty(area);
+        state.select(Some(3));
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["0", "2", "3", "5"]));
+
+        // when
+        let mut buf = Buffer::empty(area);
+        state.next();
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["0", "3", "4", "5"]));
+
+        // when
+        let mut buf = Buffer::empty(area);
+        state.next();
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_eq!(state.selected, Some(5));
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["0", "3", "4", "5"]));
+
+        // when
+        let mut buf = Buffer::empty(area);
+        state.next();
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_eq!(state.selected, Some(0));
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["0", "1", "2", "5"]));
+    }
+
+    #[test]
+    fn item_margin() {
+        // given
+        let area = Rect::new(0, 0, 5, 8);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder = ListBuilder::new(|_| ItemLayout::new(TestItem {}, 3).margin(1, 0));
+        let list = ListView::new(builder, 2);
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "     ",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+                "     ",
+                "┌───┐",
+                "│   │",
+                "└───┘",
+            ]),
+        )
+    }
+
+    #[test]
+    fn skip_unselectable_items() {
+        // given
+        let area = Rect::new(0, 0, 1, 4);
+        let mut state = ListState::default();
+        let list = || {
+            let builder = ListBuilder::new(|context| {
+                ItemLayout::new(Line::from(context.index.to_string()), 1)
+                    .selectable(context.index != 1)
+            });
+            ListView::new(builder, 4).infinite_scrolling(false)
+        };
+        state.select(Some(0));
+        list().render(area, &mut Buffer::empty(area), &mut state);
+
+        // when
+        state.next();
+        list().render(area, &mut Buffer::empty(area), &mut state);
+
+        // then
+        assert_eq!(state.selected, Some(2));
+
+        // when
+        state.previous();
+        list().render(area, &mut Buffer::empty(area), &mut state);
+
+        // then
+        assert_eq!(state.selected, Some(0));
+    }
+
+    #[test]
+    fn selectable_check_is_skipped_if_selection_unchanged() {
+        // given
+        let area = Rect::new(0, 0, 1, 3);
+        let mut state = ListState::default();
+        let built = Rc::new(Cell::new(0));
+        let list = || {
+            let built = Rc::clone(&built);
+            let builder = ListBuilder::new(move |context| {
+                built.set(built.get() + 1);
+                ItemLayout::new(Line::from(context.index.to_string()), 1)
+            });
+            ListView::new(builder, 10)
+        };
+        state.select(Some(1));
+        list().render(area, &mut Buffer::empty(area), &mut state);
+        built.set(0);
+
+        // when
+        list().render(area, &mut Buffer::empty(area), &mut state);
+
+        // then
+        assert_eq!(built.get(), 3);
+    }
+
+    #[test]
+    fn sticky_items() {
+        // given
+        let area = Rect::new(0, 0, 5, 3);
+        let mut state = ListState::default();
+        let list = |reverse| {
+            let builder = ListBuilder::new(|context| {
+                let sticky = context.index % 3 == 0;
+                let text = if sticky {
+                    format!("H{}", context.index)
+                } else {
+                    format!("item{}", context.index)
+                };
+                ItemLayout::new(Line::from(text), 1).sticky(sticky)
+            });
+            ListView::new(builder, 6).reverse(reverse)
+        };
+
+        // when
+        let mut buf = Buffer::empty(area);
+        state.select(Some(4));
+        list(false).render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["H0   ", "H3   ", "item4"]));
+
+        // when
+        let mut buf = Buffer::empty(area);
+        state.select(Some(5));
+        list(false).render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["H3   ", "item4", "item5"]));
+
+        // when
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        state.select(Some(4));
+        list(true).render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["item4", "H3   ", "H0   "]));
+    }
+
+    #[test]
+    fn sticky_item_lookup_is_cached() {
+        // given
+        let area = Rect::new(0, 0, 5, 3);
+        let mut state = ListState::default();
+        let built = Rc::new(Cell::new(0));
+        let list = || {
+            let built = Rc::clone(&built);
+            let builder = ListBuilder::new(move |context| {
+                built.set(built.get() + 1);
+                let sticky = context.index == 0;
+                ItemLayout::new(Line::from(context.index.to_string()), 1).sticky(sticky)
+            });
+            ListView::new(builder, 10_000).fixed_item_size(1)
+        };
+
+        // when
+        for selected in [0, 5_000] {
+            let mut buf = Buffer::empty(area);
+            state.select(Some(selected));
+            list().render(area, &mut buf, &mut state);
+        }
+        built.set(0);
+        let mut buf = Buffer::empty(area);
+        state.select(Some(5_001));
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["0    ", "5000 ", "5001 "]));
+        assert!(built.get() < 10);
+    }
+
+    #[test]
+    fn fixed_item_size() {
+        // given
+        let area = Rect::new(0, 0, 6, 3);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let built = Rc::new(Cell::new(0));
+        let builder = ListBuilder::new({
+            let built = Rc::clone(&built);
+            move |context| {
+                built.set(built.get() + 1);
+                (Line::from(context.index.to_string()), 5)
+            }
+        });
+        let list = ListView::new(builder, 1_000_000)
+            .fixed_item_size(1)
+            .scroll_padding(1);
+
+        // when
+        state.select(Some(999_998));
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["999997", "999998", "999999"]));
+        assert!(built.get() < 10);
+    }
+
+    #[test]
+    fn scroll_to_fraction() {
+        // given
+        let area = Rect::new(0, 0, 1, 3);
+        let mut state = ListState::default();
+        let list = || {
+            let builder = ListBuilder::new(|context| {
+                let size = if context.index < 5 { 2 } else { 1 };
+                (Line::from(context.index.to_string()), size)
+            });
+            ListView::new(builder, 10)
+        };
+
+        // when
+        let mut buf = Buffer::empty(area);
+        state.scroll_to_fraction(0.5);
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_eq!(state.selected, Some(3));
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["3", " ", "4"]));
+
+        // when
+        let mut buf = Buffer::empty(area);
+        state.scroll_to_fraction(1.0);
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_eq!(state.selected, Some(9));
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["7", "8", "9"]));
+    }
+
+    #[test]
+    fn estimated_item_size() {
+        // given
+        let area = Rect::new(0, 0, 2, 3);
+        let mut state = ListState::default();
+        let built = Rc::new(Cell::new(0));
+        let list = || {
+            let built = Rc::clone(&built);
+            let builder = ListBuilder::new(move |context| {
+                built.set(built.get() + 1);
+                (Line::from(context.index.to_string()), 2)
+            });
+            ListView::new(builder, 100).estimated_item_size(1)
+        };
+
+        // when
+        let mut buf = Buffer::empty(area);
+        state.scroll_to_fraction(0.5);
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_eq!(state.selected, Some(50));
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["50", "  ", "51"]));
+        assert!(built.get() < 10);
+        assert_eq!(state.size_index.total(), 102);
+    }
+
+    #[test]
+    fn cross_axis_scrolling() {
+        // given
+        let area = Rect::new(0, 0, 3, 2);
+        let mut state = ListState::default();
+        let list = || {
+            let builder = ListBuilder::new(|context| {
+                assert_eq!(context.cross_axis_size, 5);
+                (Line::from(format!("{0}bcd{0}", context.index)), 1)
+            });
+            ListView::new(builder, 2).virtual_cross_axis_size(5)
+        };
+
+        // when
+        let mut buf = Buffer::empty(area);
+        state.scroll_right();
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["bcd", "bcd"]));
+
+        // when
+        let mut buf = Buffer::empty(area);
+        for _ in 0..5 {
+            state.scroll_right();
+        }
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["cd0", "cd1"]));
+    }
+
+    #[test]
+    fn truncated_items_reuse_scratch_buffer() {
+        // given
+        let area = Rect::new(0, 0, 2, 3);
+        let mut state = ListState::default();
+        let list = || {
+            let builder = ListBuilder::new(|context| {
+                let lines = vec![
+                    Line::from(format!("{0}a", context.index)),
+                    Line::from(format!("{0}b", context.index)),
+                ];
+                (Text::from(lines), 2)
+            });
+            ListView::new(builder, 3)
+        };
+
+        // when
+        let mut buf = Buffer::empty(area);
+        state.select(Some(2));
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["1b", "2a", "2b"]));
+
+        // when
+        let mut buf = Buffer::empty(area);
+        state.select(Some(0));
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["0a", "0b", "1a"]));
+        assert_eq!(state.scratch.0.area, Rect::new(0, 2, 2, 2));
+    }
+
+    #[test]
+    fn debug_omits_scratch_buffer() {
+        // given
+        let area = Rect::new(0, 0, 2, 1);
+        let mut state = ListState::default();
+        let builder = ListBuilder::new(|context| (Line::from(context.index.to_string()), 2));
+        let list = ListView::new(builder, 1);
+
+        // when
+        list.render(area, &mut Buffer::empty(area), &mut state);
+        let debug = format!("{state:?}");
+
+        // then
+        assert!(
+            debug.contains("ScratchBuffer { area: Rect { x: 0, y: 0, width: 2, height: 2 }, .. }")
+        );
+        assert!(!debug.contains("content"));
+    }
+
+    struct RowsItem(usize);
+    impl Widget for RowsItem {
+        fn render(self, area: Rect, buf: &mut Buffer) {
+            self.render_clipped(area, Clip::full(area.as_size()), buf);
+        }
+    }
+    impl ClippedWidget for RowsItem {
+        fn render_clipped(self, area: Rect, clip: Clip, buf: &mut Buffer) {
+            assert_eq!(clip.full_size, Size::new(2, 3));
+            for (y, row) in (area.top()..area.bottom()).zip(clip.offset.y..) {
+                buf.set_string(area.x, y, format!("{}{row}", self.0), Style::default());
+            }
+        }
+    }
+
+    #[test]
+    fn clipped_widget() {
+        // given
+        let area = Rect::new(0, 0, 2, 5);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder =
+            ListBuilder::new(|context| ItemLayout::new(RowsItem(context.index), 3).margin(1, 0))
+                .clipped();
+        let list = ListView::new(builder, 3);
+
+        // when
+        state.select(Some(2));
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["12", "  ", "20", "21", "22"]));
+    }
+
+    struct PlainItem(usize);
+    impl Widget for PlainItem {
+        fn render(self, area: Rect, buf: &mut Buffer) {
+            Text::from(vec![
+                Line::from(format!("{}a", self.0)),
+                Line::from(format!("{}b", self.0)),
+            ])
+            .render(area, buf);
+        }
+    }
+    impl ClippedWidget for PlainItem {}
+
+    #[test]
+    fn clipped_widget_default() {
+        // given
+        let area = Rect::new(0, 0, 3, 3);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder = ListBuilder::new(|context| (PlainItem(context.index), 2)).clipped();
+        let list = ListView::new(builder, 3).style(Style::default().bg(Color::Blue));
+
+        // when
+        state.select(Some(2));
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        let mut expected = Buffer::with_lines(vec!["1b ", "2a ", "2b "]);
+        expected.set_style(area, Style::default().bg(Color::Blue));
+        assert_buffer_eq(buf, expected);
+    }
+
+    #[test]
+    fn loading_placeholder() {
+        // given
+        let area = Rect::new(0, 0, 2, 5);
+        let mut state = ListState::default();
+        let ready = Rc::new(Cell::new(false));
+        let list = || {
+            let builder =
+                ListBuilder::new(|context| (Line::from(format!("r{}", context.index)), 2));
+            let ready = Rc::clone(&ready);
+            ListView::new(builder, 6).loading_placeholder(
+                move |_| ready.get(),
+                1,
+                |_| Line::from(".."),
+            )
+        };
+
+        // when
+        let mut buf = Buffer::empty(area);
+        state.select(Some(1));
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["..", "..", "..", "..", ".."]));
+
+        // when
+        let mut buf = Buffer::empty(area);
+        ready.set(true);
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["  ", "r1", "  ", "r2", "  "]));
+    }
+
+    #[test]
+    fn item_block() {
+        // given
+        let area = Rect::new(0, 0, 5, 4);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder = ListBuilder::new(|context| {
+            assert_eq!(context.cross_axis_size, 3);
+            (Line::from(context.index.to_string()), 1)
+        });
+        let list = ListView::new(builder, 3).item_block(|_| Block::bordered());
+
+        // when
+        state.select(Some(2));
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec!["└───┘", "┌───┐", "│2  │", "└───┘"]),
+        );
+    }
+
+    #[test]
+    fn item_block_is_built_once_per_item() {
+        // given
+        let area = Rect::new(0, 0, 5, 9);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let built = Rc::new(Cell::new(0));
+        let builder = ListBuilder::new(|context| (Line::from(context.index.to_string()), 1));
+        let list = ListView::new(builder, 3).item_block({
+            let built = Rc::clone(&built);
+            move |_| {
+                built.set(built.get() + 1);
+                Block::bordered()
+            }
+        });
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_eq!(built.get(), 3);
+    }
+
+    #[test]
+    fn truncation_marker() {
+        // given
+        let area = Rect::new(0, 0, 3, 4);
+        let mut state = ListState::default();
+        let list = |scroll_axis, marker| {
+            let builder = ListBuilder::new(|context| (Line::from(context.index.to_string()), 3));
+            ListView::new(builder, 3)
+                .scroll_axis(scroll_axis)
+                .truncation_marker(marker)
+        };
+
+        // when
+        let mut buf = Buffer::empty(area);
+        let marker = TruncationMarker::Symbol("…".to_string(), Style::default());
+        list(ScrollAxis::Vertical, marker).render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["0  ", "   ", "   ", "1… "]));
+
+        // when
+        let area = Rect::new(0, 0, 4, 3);
+        let mut buf = Buffer::empty(area);
+        let marker = TruncationMarker::default();
+        list(ScrollAxis::Horizontal, marker).render(area, &mut buf, &mut state);
+
+        // then
+        let mut expected = Buffer::with_lines(vec!["0  1", "    ", "    "]);
+        expected.set_style(Rect::new(3, 0, 1, 3), Modifier::DIM);
+        assert_buffer_eq(buf, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn restore_serialized_state() {
+        // given
+        let area = Rect::new(0, 0, 1, 3);
+        let list = |item_count| {
+            let builder = ListBuilder::new(|context| (Line::from(context.index.to_string()), 1));
+            ListView::new(builder, item_count)
+        };
+        let mut state = ListState::default();
+        state.select(Some(5));
+        list(10).render(area, &mut Buffer::empty(area), &mut state);
+
+        // when
+        let json = serde_json::to_string(&state).unwrap();
+        let mut restored: ListState = serde_json::from_str(&json).unwrap();
+        let mut buf = Buffer::empty(area);
+        list(10).render(area, &mut buf, &mut restored);
+
+        // then
+        assert_eq!(json, r#"{"selected":5,"offset":3,"first_truncated":0}"#);
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["3", "4", "5"]));
+
+        // when
+        let mut restored: ListState = serde_json::from_str(&json).unwrap();
+        let mut buf = Buffer::empty(area);
+        list(2).render(area, &mut buf, &mut restored);
+
+        // then
+        assert_eq!(restored.selected, Some(1));
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["0", "1", " "]));
+    }
+
+    #[test]
+    fn masonry() {
+        // given
+        let area = Rect::new(0, 0, 4, 3);
+        let mut state = ListState::default();
+        let list = || {
+            let builder = ListBuilder::new(|context| {
+                let size = [2, 1, 1, 2, 1][context.index];
+                (Line::from(context.index.to_string()), size)
+            });
+            ListView::new(builder, 5).masonry(2)
+        };
+
+        // when
+        let mut buf = Buffer::empty(area);
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["0 1 ", "  2 ", "3 4 "]));
+
+        // when
+        state.select(Some(1));
+        state.next_in_column();
+        state.next_in_column();
+
+        // then
+        assert_eq!(state.selected, Some(4));
+
+        // when
+        state.next_in_column();
+        let mut buf = Buffer::empty(area);
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_eq!(state.selected, Some(4));
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["0 1 ", "  2 ", "3 4 "]));
+    }
+
+    #[test]
+    fn masonry_highlight_style() {
+        // given
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder = ListBuilder::new(|context| (Line::from(context.index.to_string()), 1));
+        let list = ListView::new(builder, 4)
+            .masonry(2)
+            .highlight_symbol(">")
+            .highlight_style(Style::default().bg(Color::Red));
+
+        // when
+        state.select(Some(3));
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        let mut expected = Buffer::with_lines(vec!["0 1 ", "2 3 "]);
+        expected.set_style(Rect::new(2, 1, 2, 1), Style::default().bg(Color::Red));
+        assert_buffer_eq(buf, expected);
+    }
+
+    #[test]
+    fn scrollbar() {
+        // given
+        let area = Rect::new(0, 0, 2, 4);
+        let mut state = ListState::default();
+        let list = || {
+            let builder = ListBuilder::new(|context| {
+                let size = if context.index == 0 { 4 } else { 1 };
+                (Line::from(context.index.to_string()), size)
+            });
+            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None);
+            ListView::new(builder, 5).scrollbar(scrollbar)
+        };
+
+        // when
+        let mut buf = Buffer::empty(area);
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["0█", " █", " ║", " ║"]));
+
+        // when
+        let mut buf = Buffer::empty(area);
+        state.select(Some(4));
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["1║", "2║", "3█", "4█"]));
+    }
+
+    #[test]
+    fn scrollbar_left_orientation_uses_last_column() {
+        // given
+        let area = Rect::new(0, 0, 2, 4);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder = ListBuilder::new(|context| (Line::from(context.index.to_string()), 1));
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalLeft)
+            .begin_symbol(None)
+            .end_symbol(None);
+        let list = ListView::new(builder, 8).scrollbar(scrollbar);
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["0█", "1█", "2║", "3║"]));
+    }
+
+    #[test]
+    fn scrollbar_reverse() {
+        // given
+        let area = Rect::new(0, 0, 2, 4);
+        let mut state = ListState::default();
+        let list = || {
+            let builder = ListBuilder::new(|context| (Line::from(context.index.to_string()), 1));
+            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None);
+            ListView::new(builder, 8).reverse(true).scrollbar(scrollbar)
+        };
+
+        // when
+        let mut buf = Buffer::empty(area);
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(buf, Buffer::with_lines(vec!["3║", "2║", "1█", "0█"]));
+    }
+
+    #[test]
+    fn scrollbar_in_empty_area() {
+        // given
+        let area = Rect::new(0, 0, 0, 4);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder = ListBuilder::new(|context| (Line::from(context.index.to_string()), 1));
+        let list = ListView::new(builder, 8).scrollbar(Scrollbar::default());
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_eq!(buf.area, area);
+    }
+
+    #[test]
+    fn overflow_indicators() {
+        // given
+        let area = Rect::new(0, 0, 10, 4);
+        let mut state = ListState::default();
+        let list = || {
+            let builder = ListBuilder::new(|context| (Line::from(context.index.to_string()), 1));
+            let indicators = OverflowIndicators::default().placement(OverflowPlacement::Inside);
+            ListView::new(builder, 10).overflow_indicators(indicators)
+        };
+
+        // when
+        let mut buf = Buffer::empty(area);
+        state.select(Some(5));
+        list().render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![" ▲ 4 more ", "4         ", "5         ", " ▼ 4 more "]),
+        );
+    }
+
+    #[test]
+    fn overflow_indicators_next_to_block_title() {
+        // given
+        let area = Rect::new(0, 0, 24, 4);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder = ListBuilder::new(|context| (Line::from(context.index.to_string()), 1));
+        let list = ListView::new(builder, 10)
+            .block(Block::bordered().title(Line::from("List").centered()))
+            .overflow_indicators(OverflowIndicators::default());
+
+        // when
+        state.select(Some(9));
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec![
+                "┌─────────List─▲ 8 more┐",
+                "│8                     │",
+                "│9                     │",
+                "└──────────────────────┘",
+            ]),
+        );
+    }
+
+    #[test]
+    fn empty_placeholder() {
+        // given
+        let area = Rect::new(0, 0, 7, 3);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder = ListBuilder::new(|_| (Line::from("item"), 1));
+        let list = ListView::new(builder, 0)
+            .block(Block::bordered())
+            .empty_placeholder(|area| Line::from(format!("w={0}", area.width)));
+
+        // when
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        assert_buffer_eq(
+            buf,
+            Buffer::with_lines(vec!["┌─────┐", "│w=5  │", "└─────┘"]),
+        );
+    }
+
+    #[test]
+    fn highlight_symbol_and_style() {
+        // given
+        let area = Rect::new(0, 0, 4, 3);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder = ListBuilder::new(|context| (Line::from(context.index.to_string()), 2));
+        let list = ListView::new(builder, 3)
+            .highlight_symbol(">>")
+            .highlight_style(Style::new().add_modifier(Modifier::BOLD));
+
+        // when
+        state.select(Some(1));
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        let mut expected = Buffer::with_lines(vec!["    ", ">>1 ", "    "]);
+        expected.set_style(
+            Rect::new(0, 1, 4, 2),
+            Style::new().add_modifier(Modifier::BOLD),
+        );
+        assert_buffer_eq(buf, expected);
+    }
+
+    #[test]
+    fn list_styles() {
+        // given
+        let area = Rect::new(0, 0, 1, 4);
+        let mut buf = Buffer::empty(area);
+        let mut state = ListState::default();
+        let builder = ListBuilder::new(|context| {
+            ItemLayout::new(Line::from(context.index.to_string()), 1).selectable(context.index != 3)
+        });
+        let (even, odd, selected, hovered, disabled) = (
+            Style::new().bg(Color::Black),
+            Style::new().bg(Color::White),
+            Style::new().bg(Color::Red),
+            Style::new().bg(Color::Green),
+            Style::new().bg(Color::Blue),
+        );
+        let styles = ListStyles::default()
+            .even(even)
+            .odd(odd)
+            .selected(selected)
+            .hovered(hovered)
+            .disabled(disabled);
+        let list = ListView::new(builder, 4).styles(styles);
+
+        // when
+        state.select(Some(1));
+        state.hovered = Some(2);
+        list.render(area, &mut buf, &mut state);
+
+        // then
+        let mut expected = Buffer::with_lines(vec!["0", "1", "2", "3"]);
+        for (y, style) in [even, selected, hovered, disabled].into_iter().enumerate() {
+            expected.set_style(Rect::new(0, y as u16, 1, 1), style);
+        }
+        assert_buffer_eq(buf, expected);
+    }
+
     fn assert_buffer_eq(actual: Buffer, expected: Buffer) {
         if actual.area != expected.area {
             panic!(